@@ -19,6 +19,38 @@ $
   frac(a c + b d, c^2 + d^2) + frac(b c - a d, c^2 + d^2)i=\
 $
 
+Computing $c^2 + d^2$ directly overflows or underflows for large or small parts, so the division
+is done with Smith's algorithm, in the robust form by Baudin and Smith. For $|d| <= |c|$:
+$
+  r = d/c, quad t = inv(c + d r) \
+  frac(a + b i, c + d i) = (a + b r)t + (b - a r)t i
+$
+For $|d| > |c|$ the roles of $c$ and $d$ are swapped. Operands near the edges of the exponent range are
+first scaled by a power of two, which is undone afterwards.
+
+= Absolute value
+$
+  |z| = sqrt(a^2 + b^2) = "hypot"(a, b)
+$
+where hypot computes the root without overflow or underflow in $a^2 + b^2$.
+
+= Norms and projection
+$
+  ||z||_1 = |a| + |b|, quad ||z||_infinity = max(|a|, |b|), quad
+  "proj"(z) = cases(infinity + 0 i "sign"(b) & "if" a "or" b "is infinite", z & "otherwise")
+$
+
+= Remainder
+The quotient is rounded towards zero on both parts:
+$
+  z_1 mod z_2 = z_1 - z_2 ("trunc"("Re"(frac(z_1, z_2))) + "trunc"("Im"(frac(z_1, z_2)))i)
+$
+
+= Fused multiply-add
+$
+  z_1 z_2 + z_3 = (a c - b d + "Re"(z_3)) + (a d + b c + "Im"(z_3))i
+$
+
 = Square root
 If $b$ is positive:
 $
@@ -30,12 +62,19 @@ $
   sqrt(z)=sqrt(frac(a+sqrt(a^2+b^2), 2))-sqrt(frac(-a+sqrt(a^2+b^2), 2))i
 $
 
+To avoid cancellation only the larger of the two roots is computed this way, following Kahan:
+$
+  t = sqrt(frac(|a| + |z|, 2)), quad
+  sqrt(z) = cases(t + frac(b, 2t) i & "if" a >= 0, frac(|b|, 2t) + "sign"(b) t i & "if" a < 0)
+$
+
 = Inverse
 $
   inv(z) =\
   frac(a - b i, |z|^2)=\
   frac(a, |z|^2) - frac(b, |z|^2) i
 $
+The inverse is computed as $frac(1, z)$ with the division above.
 
 = Natural Logarithm
 $
@@ -44,6 +83,27 @@ $
   ln|z|+ln(e^(i theta))=\
   ln|z|+i theta
 $
+where $theta = arg(z) = "atan2"(b, a)$, which keeps the sign of a zero $b$ so both sides of the branch
+cut along the negative real axis are reachable.
+
+$ln|z|$ cancels when $|z|$ is close to 1, so there it is computed with $a >= b >= 0$ as
+$
+  ln|z| = 1/2 ln(1 + (a - 1)(a + 1) + b^2)
+$
+where $a - 1$ is exact and the rounding error of the product is added back with a fused multiply-add.
+
+= Cancellation-free variants
+For small $z$ adding or subtracting 1 loses the low bits of the result, so these are computed from the
+real functions $"ln1p"(x) = ln(1 + x)$ and $"expm1"(x) = e^x - 1$:
+$
+  ln(1 + z) & = 1/2 "ln1p"(a(2 + a) + b^2) + "atan2"(b, 1 + a)i \
+  e^z - 1 & = "expm1"(a) cos(b) + (cos(b) - 1) + e^a sin(b)i \
+  cos(z) - 1 & = (cos(a) - 1)cosh(b) + (cosh(b) - 1) - sin(a)sinh(b)i
+$
+with
+$
+  cos(x) - 1 = -2 sin^2(x/2), quad cosh(x) - 1 = 2 sinh^2(x/2)
+$
 
 = Exponentiation
 
@@ -54,6 +114,16 @@ $
   z_1^(c) e^(ln(z_1)d i)=\
 $
 
+= Branches
+The logarithm has one value per sheet, and roots and powers inherit that:
+$
+  ln_k (z) & = ln|z| + (arg(z) + 2 pi k)i \
+  z^w_k & = e^(w ln_k (z)) \
+  root(n, z)_k & = root(n, |z|) e^(i frac(arg(z) + 2 pi k, n)), quad k = 0, ..., n - 1
+$
+The roots are computed as the principal root times the roots of unity $e^(2 pi i k/n)$, which are
+exact where they lie on the axes.
+
 = Trig
 
 $
@@ -65,6 +135,14 @@ $
   csc(z) & =inv(sin(z)) \
 $
 
+The trigonometric functions are computed through the hyperbolic ones, so they share their special
+values and accuracy:
+$
+  sin(z) & =-i sinh(i z) \
+  cos(z) & =cosh(i z) \
+  tan(z) & =-i tanh(i z)
+$
+
 = Inverse Trig
 
 $
@@ -76,6 +154,24 @@ $
   "arccsc"(z) & =arcsin(inv(z)) \
 $
 
+These formulas cancel badly near the real axis and near $plus.minus 1$, and overflow for large $|z|$.
+$arcsin$ and $arccos$ are computed with the algorithm of Hull, Fairgrieve and Tang instead. With
+$x = |a|$, $y = |b|$, $r = |z + 1|$, $s = |z - 1|$ and
+$
+  alpha = frac(r + s, 2), quad beta = frac(x, alpha)
+$
+$
+  arcsin(z) & = arcsin(beta) + ln(alpha + sqrt(alpha^2 - 1)) i \
+  arccos(z) & = arccos(beta) - ln(alpha + sqrt(alpha^2 - 1)) i
+$
+with the signs of $a$ and $b$ restored afterwards. For $beta$ close to 1 the real part is computed as an
+arctangent, and for $alpha$ close to 1 the imaginary part as $ln(1 + (alpha - 1) + sqrt((alpha - 1)(alpha + 1)))$
+with $alpha - 1$ computed without cancellation. Inputs close to the real axis, very large or very small
+use asymptotic forms. $arctan(z) = -i "arctanh"(i z)$.
+
+The branch cuts are the parts of the real axis below $-1$ and above $1$ for $arcsin$ and $arccos$,
+and the parts of the imaginary axis below $-i$ and above $i$ for $arctan$.
+
 = Hyperbolic Trig
 
 $
@@ -87,6 +183,13 @@ $
   csch(z) & =inv(sin(z)) \
 $
 
+$tanh$ uses Kahan's form, which doesn't overflow for large $a$:
+$
+  t = tan(b), quad beta = 1 + t^2, quad s = sinh(a), quad rho = sqrt(1 + s^2) \
+  tanh(z) = frac(beta rho s + t i, 1 + beta s^2)
+$
+For $|a| > -ln(epsilon)$ this is $plus.minus 1 + 4 sin(b) cos(b) e^(-2|a|) i$.
+
 = Inverse Hyperbolic Trig
 
 $
@@ -97,3 +200,83 @@ $
   "arcsech"(z) & ="arccosh"(inv(z)) \
   "arccsch"(z) & ="arcsinh"(inv(z)) \
 $
+
+$"arcsinh"(z) = -i arcsin(i z)$ and $"arccosh"(z) = plus.minus i arccos(z)$ are computed from the
+algorithm above. $"arctanh"$ uses Kahan's form, with $x = |a|$ and $y = |b|$:
+$
+  "Re"("arctanh"(z)) & = 1/4 ln(1 + frac(4x, (x - 1)^2 + y^2)) \
+  "Im"("arctanh"(z)) & = 1/2 "atan2"(2y, (1 - x)(1 + x) - y^2)
+$
+
+The branch cuts are the part of the real axis below $1$ for $"arccosh"$, the parts of the real axis
+below $-1$ and above $1$ for $"arctanh"$ and the parts of the imaginary axis below $-i$ and above $i$
+for $"arcsinh"$.
+
+= Polar form
+
+$
+  z & =r e^(i theta)=r cos(theta) + r sin(theta)i \
+  r & =|z| \
+  theta & =arg(z)
+$
+
+$
+  z_1 z_2 & =r_1 r_2 e^(i(theta_1 + theta_2)) \
+  frac(z_1, z_2) & =frac(r_1, r_2) e^(i(theta_1 - theta_2)) \
+  z^n & =r^n e^(i n theta)
+$
+
+Angles are brought back into $(-pi, pi]$ by subtracting whole turns:
+$
+  theta' = theta - 2 pi ceil(frac(theta - pi, 2 pi))
+$
+
+= Compensated summation
+Kahan–Neumaier summation, applied to the real and imaginary parts separately:
+$
+  t & = s + x_k \
+  c & = c + cases((s - t) + x_k & "if" |s| >= |x_k|, (x_k - t) + s & "otherwise") \
+  s & = t \
+  sum x_k & approx s + c
+$
+
+= Gaussian integers
+Division rounds the exact quotient to the nearest Gaussian integer, so the remainder is small:
+$
+  q = "round"(frac(z_1 overline(z_2), N(z_2))), quad r = z_1 - q z_2, quad N(r) <= N(z_2) / 2
+$
+where $N(a + b i) = a^2 + b^2$. A rational prime $p = 4k + 1$ splits as $p = (a + b i)(a - b i)$,
+with $a$ the first remainder below $sqrt(p)$ in the Euclidean algorithm on $p$ and $x$, where
+$x^2 equiv -1 mod p$.
+
+= Quadratic integers
+Eisenstein integers are $a + b omega$ with $omega = e^(2 pi i slash 3)$ and $omega^2 = -1 - omega$:
+$
+  (a + b omega)(c + d omega) = (a c - b d) + (a d + b c - b d) omega, quad N(a + b omega) = a^2 - a b + b^2
+$
+and the conjugate of $a + b omega$ is $(a - b) - b omega$. Rounding both coefficients of the exact
+quotient leaves $N(r) <= 3/4 N(z_2)$. In $ZZ[sqrt(D)]$ with $D < 0$ squarefree,
+$
+  (a + b sqrt(D))(c + d sqrt(D)) = (a c + D b d) + (a d + b c) sqrt(D), quad N(a + b sqrt(D)) = a^2 - D b^2
+$
+and rounding gives $N(r) <= (1 - D) / 4 N(z_2)$, which is below $N(z_2)$ only for $D = -1$ and $D = -2$.
+
+= Fixed point
+A Q15 or Q31 part is the integer $n$ standing for $n slash 2^15$ or $n slash 2^31$. Products are
+accumulated exactly and rounded once, $(a c - b d + 2^(k - 1)) >> k$ for $k$ fractional bits. The
+magnitude is approximated with alpha max plus beta min,
+$
+  |z| approx 15/16 max(|a|, |b|) + 15/32 min(|a|, |b|)
+$
+which is at most 6.25% off. CORDIC rotates by $plus.minus arctan(2^(-i))$ using only shifts,
+$
+  x_(i + 1) = x_i - d_i y_i 2^(-i), quad y_(i + 1) = y_i + d_i x_i 2^(-i), quad theta_(i + 1) = theta_i - d_i arctan(2^(-i))
+$
+with $d_i$ the sign of $theta_i$ to rotate, or the opposite of the sign of $y_i$ to find the
+argument. The iterations scale by $product_i sqrt(1 + 4^(-i)) approx 1.6468$, which is divided out
+beforehand.
+
+= Special values
+Infinities, NaNs and signed zeros follow Annex G of the C99 standard. Where the formulas above would
+overflow although the result is finite, like $e^z$, $sinh(z)$ and $cosh(z)$ just above the overflow
+threshold, the result is computed as $(e^(a/2) cos(b)) e^(a/2) + (e^(a/2) sin(b)) e^(a/2) i$.
@@ -0,0 +1,185 @@
+// The other branches of the multi-valued functions, for when the principal value isn't enough
+use crate::overloading::multiply;
+use crate::{Complex, Numbers};
+use core::iter::FusedIterator;
+use num_traits::Float;
+
+impl<T: Float> Complex<T> {
+    /// Returns the principal n-th root of this [`Complex`], the root with the argument closest to
+    /// zero.
+    ///
+    /// The branch cut lies along the negative real axis, so the principal cube root of -8 is
+    /// 1 + 1.732i and not -2. Returns NaN for n = 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(0.0, 8.0);
+    /// let root = z.nth_root(3);
+    ///
+    /// assert!((root - Complex::new(3f64.sqrt(), 1.0)).abs() < 1e-15);
+    /// ```
+    pub fn nth_root(self, n: u32) -> Self {
+        match n {
+            0 => Self::new(T::nan(), T::nan()),
+            1 => self,
+            2 => Self::sqrt(self),
+            _ => {
+                let n = T::from(n).unwrap();
+                Self::from_polar(T::powf(Self::abs(self), T::one() / n), Self::arg(self) / n)
+            }
+        }
+    }
+
+    /// Returns an iterator over all n n-th roots of this [`Complex`].
+    ///
+    /// The principal root comes first, the others follow counterclockwise. Roots that lie on the
+    /// axes relative to the principal root are exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let roots: Vec<_> = Complex::new(16.0, 0.0).roots(4).collect();
+    ///
+    /// assert_eq!(
+    ///     roots,
+    ///     [
+    ///         Complex::new(2.0, 0.0),
+    ///         Complex::new(0.0, 2.0),
+    ///         Complex::new(-2.0, 0.0),
+    ///         Complex::new(0.0, -2.0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn roots(self, n: u32) -> Roots<T> {
+        Roots::new(Self::nth_root(self, n), n)
+    }
+
+    /// Returns an iterator over the n n-th roots of unity, starting at 1 and going
+    /// counterclockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let roots: Vec<Complex<f64>> = Complex::roots_of_unity(4).collect();
+    ///
+    /// assert_eq!(
+    ///     roots,
+    ///     [
+    ///         Complex::new(1.0, 0.0),
+    ///         Complex::new(0.0, 1.0),
+    ///         Complex::new(-1.0, 0.0),
+    ///         Complex::new(0.0, -1.0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn roots_of_unity(n: u32) -> Roots<T> {
+        Roots::new(Self::new(T::one(), T::zero()), n)
+    }
+
+    /// Returns the natural logarithm of this [`Complex`] on the k-th sheet.
+    ///
+    /// This is the principal value [`Complex::ln`] plus 2 PI k i, so k = 0 is the principal value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z = Complex::new(-1.0, 0.0);
+    ///
+    /// assert_eq!(z.ln_branch(0), Complex::new(0.0, PI));
+    /// assert_eq!(z.ln_branch(1), Complex::new(0.0, 3.0 * PI));
+    /// assert_eq!(z.ln_branch(-1), Complex::new(0.0, -PI));
+    /// ```
+    pub fn ln_branch(self, k: i64) -> Self {
+        let turns = T::from(k).unwrap() * T::two() * T::pi();
+        let ln = Self::ln(self);
+        Self::new(ln.real, ln.imag + turns)
+    }
+
+    /// Returns this [`Complex`] raised to a complex power, using the k-th sheet of the logarithm.
+    ///
+    /// This is e^(exponent ln_branch(k)), so k = 0 gives the principal value of [`Complex::powc`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(4.0, 0.0);
+    /// let half = Complex::new(0.5, 0.0);
+    ///
+    /// assert!((z.powc_branch(half, 0) - Complex::new(2.0, 0.0)).abs() < 1e-15);
+    /// assert!((z.powc_branch(half, 1) - Complex::new(-2.0, 0.0)).abs() < 1e-15);
+    /// ```
+    pub fn powc_branch(self, exponent: Self, k: i64) -> Self {
+        Self::exp(multiply(exponent, Self::ln_branch(self, k)))
+    }
+}
+
+/// Iterator over the n-th roots of a [`Complex`], created by [`Complex::roots`] and
+/// [`Complex::roots_of_unity`].
+#[derive(Debug, Clone)]
+pub struct Roots<T: Float> {
+    principal: Complex<T>,
+    n: u32,
+    k: u32,
+}
+
+impl<T: Float> Roots<T> {
+    fn new(principal: Complex<T>, n: u32) -> Self {
+        Self { principal, n, k: 0 }
+    }
+}
+
+impl<T: Float> Iterator for Roots<T> {
+    type Item = Complex<T>;
+
+    fn next(&mut self) -> Option<Complex<T>> {
+        if self.k >= self.n {
+            return None;
+        }
+        let root = multiply(self.principal, unit_root(self.k, self.n));
+        self.k += 1;
+        Some(root)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.n - self.k) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Float> ExactSizeIterator for Roots<T> {}
+
+impl<T: Float> FusedIterator for Roots<T> {}
+
+/// Returns e^(2 PI i k / n), exact on the axes and in conjugate pairs elsewhere.
+fn unit_root<T: Float>(k: u32, n: u32) -> Complex<T> {
+    let (k, n) = (u64::from(k), u64::from(n));
+    if 4 * k % n == 0 {
+        return match 4 * k / n {
+            0 => Complex::new(T::one(), T::zero()),
+            1 => Complex::new(T::zero(), T::one()),
+            2 => Complex::new(-T::one(), T::zero()),
+            _ => Complex::new(T::zero(), -T::one()),
+        };
+    }
+
+    // Going clockwise for the lower half keeps the angle small and the roots symmetric
+    let turn = T::two() * T::pi();
+    let angle = if 2 * k > n {
+        -turn * T::from(n - k).unwrap() / T::from(n).unwrap()
+    } else {
+        turn * T::from(k).unwrap() / T::from(n).unwrap()
+    };
+    Complex::cis(angle)
+}
@@ -0,0 +1,290 @@
+// Checked versions of the operations that can divide by zero, hit a pole, overflow or produce NaN
+use crate::Complex;
+use crate::overloading::{divide, multiply, remainder};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use num_traits::{Float, Zero};
+
+/// Error returned by the `checked_*` functions of [`Complex`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComplexError {
+    /// The divisor is zero.
+    DivisionByZero,
+    /// The function has a pole at the argument, like ln(0) or arctanh(1).
+    Pole,
+    /// The arguments are finite but the result is too large to represent.
+    Overflow,
+    /// An argument is NaN, or the operation is undefined and produced NaN.
+    NaN,
+}
+
+impl Display for ComplexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            ComplexError::DivisionByZero => "division by zero",
+            ComplexError::Pole => "function evaluated at a pole",
+            ComplexError::Overflow => "result overflowed",
+            ComplexError::NaN => "result is not a number",
+        })
+    }
+}
+
+impl core::error::Error for ComplexError {}
+
+/// Fails if any of the arguments has a NaN part.
+fn check_arguments<T: Float>(arguments: &[Complex<T>]) -> Result<(), ComplexError> {
+    if arguments.iter().any(|z| z.real.is_nan() || z.imag.is_nan()) {
+        Err(ComplexError::NaN)
+    } else {
+        Ok(())
+    }
+}
+
+/// Fails if the result has an infinite part although all arguments were finite, or a NaN part.
+///
+/// Overflow is checked first, an intermediate overflow like inf - inf can leave a NaN next to an
+/// infinity.
+fn check_result<T: Float>(
+    arguments: &[Complex<T>],
+    result: Complex<T>,
+) -> Result<Complex<T>, ComplexError> {
+    let finite = arguments
+        .iter()
+        .all(|z| z.real.is_finite() && z.imag.is_finite());
+    if finite && (result.real.is_infinite() || result.imag.is_infinite()) {
+        Err(ComplexError::Overflow)
+    } else if result.real.is_nan() || result.imag.is_nan() {
+        Err(ComplexError::NaN)
+    } else {
+        Ok(result)
+    }
+}
+
+/// Checks a function of one argument that has poles at the given points.
+fn check_unary<T: Float>(
+    z: Complex<T>,
+    poles: &[Complex<T>],
+    function: fn(Complex<T>) -> Complex<T>,
+) -> Result<Complex<T>, ComplexError> {
+    check_arguments(&[z])?;
+    if poles.contains(&z) {
+        return Err(ComplexError::Pole);
+    }
+    check_result(&[z], function(z))
+}
+
+impl<T: Float> Complex<T> {
+    /// Adds two [`Complex`] numbers, failing on overflow or NaN.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ComplexError> {
+        check_arguments(&[self, rhs])?;
+        check_result(&[self, rhs], self + rhs)
+    }
+
+    /// Subtracts two [`Complex`] numbers, failing on overflow or NaN.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ComplexError> {
+        check_arguments(&[self, rhs])?;
+        check_result(&[self, rhs], self - rhs)
+    }
+
+    /// Multiplies two [`Complex`] numbers, failing on overflow or NaN.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ComplexError> {
+        check_arguments(&[self, rhs])?;
+        check_result(&[self, rhs], multiply(self, rhs))
+    }
+
+    /// Divides two [`Complex`] numbers, failing when dividing by zero, on overflow or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::{Complex, ComplexError};
+    ///
+    /// let z = Complex::new(3.0, 4.0);
+    ///
+    /// assert_eq!(z.checked_div(Complex::new(0.0, 2.0)), Ok(Complex::new(2.0, -1.5)));
+    /// assert_eq!(z.checked_div(Complex::new(0.0, 0.0)), Err(ComplexError::DivisionByZero));
+    /// assert_eq!(
+    ///     Complex::new(1e300, 0.0).checked_div(Complex::new(1e-300, 0.0)),
+    ///     Err(ComplexError::Overflow)
+    /// );
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ComplexError> {
+        check_arguments(&[self, rhs])?;
+        if rhs.is_zero() {
+            return Err(ComplexError::DivisionByZero);
+        }
+        check_result(&[self, rhs], divide(self, rhs))
+    }
+
+    /// Returns the remainder of dividing two [`Complex`] numbers, failing when dividing by zero or
+    /// on NaN.
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, ComplexError> {
+        check_arguments(&[self, rhs])?;
+        if rhs.is_zero() {
+            return Err(ComplexError::DivisionByZero);
+        }
+        check_result(&[self, rhs], remainder(self, rhs))
+    }
+
+    /// Returns the inverse of this [`Complex`], failing for zero, on overflow or NaN.
+    pub fn checked_inv(self) -> Result<Self, ComplexError> {
+        Self::new(T::one(), T::zero()).checked_div(self)
+    }
+
+    /// Returns e raised to the power of this [`Complex`], failing on overflow or NaN.
+    pub fn checked_exp(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[], Self::exp)
+    }
+
+    /// Returns the natural logarithm of this [`Complex`], failing for zero or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::{Complex, ComplexError};
+    ///
+    /// assert_eq!(Complex::new(1.0, 0.0).checked_ln(), Ok(Complex::new(0.0, 0.0)));
+    /// assert_eq!(Complex::new(0.0, 0.0).checked_ln(), Err(ComplexError::Pole));
+    /// assert_eq!(Complex::new(f64::NAN, 1.0).checked_ln(), Err(ComplexError::NaN));
+    /// ```
+    pub fn checked_ln(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[Self::zero()], Self::ln)
+    }
+
+    /// Returns the logarithm base 10 of this [`Complex`], failing for zero or NaN.
+    pub fn checked_log(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[Self::zero()], Self::log)
+    }
+
+    /// Returns the logarithm base n of this [`Complex`], failing for zero, for a base of zero or
+    /// one, or when the base is negative or NaN.
+    ///
+    /// ln(n) has a pole at a base of zero, so that fails with [`ComplexError::Pole`] instead of
+    /// returning zero. An infinite base gives zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::{Complex, ComplexError};
+    ///
+    /// let z = Complex::new(8.0, 0.0);
+    ///
+    /// assert_eq!(z.checked_logn(2.0), Ok(z.logn(2.0)));
+    /// assert_eq!(z.checked_logn(0.0), Err(ComplexError::Pole));
+    /// assert_eq!(z.checked_logn(f64::INFINITY), Ok(Complex::new(0.0, 0.0)));
+    /// ```
+    pub fn checked_logn(self, base: T) -> Result<Self, ComplexError> {
+        let base_complex = Self::new(base, T::zero());
+        check_arguments(&[self, base_complex])?;
+        if self.is_zero() || base.is_zero() {
+            return Err(ComplexError::Pole);
+        }
+        if base == T::one() {
+            return Err(ComplexError::DivisionByZero);
+        }
+        check_result(&[self, base_complex], Self::logn(self, base))
+    }
+
+    /// Raises this [`Complex`] to an integer power, failing for a negative power of zero, on
+    /// overflow or NaN.
+    pub fn checked_powi(self, exponent: i64) -> Result<Self, ComplexError> {
+        check_arguments(&[self])?;
+        if self.is_zero() && exponent < 0 {
+            return Err(ComplexError::Pole);
+        }
+        check_result(&[self], Self::powi(self, exponent))
+    }
+
+    /// Raises this [`Complex`] to a real power, failing for a negative power of zero, on overflow
+    /// or NaN.
+    ///
+    /// Zero raised to a positive power is zero, and anything raised to the power of zero is one.
+    pub fn checked_powf(self, exponent: T) -> Result<Self, ComplexError> {
+        let exponent_complex = Self::new(exponent, T::zero());
+        check_arguments(&[self, exponent_complex])?;
+        if exponent.is_zero() {
+            return Ok(Self::new(T::one(), T::zero()));
+        }
+        if self.is_zero() {
+            return if exponent > T::zero() {
+                Ok(Self::zero())
+            } else {
+                Err(ComplexError::Pole)
+            };
+        }
+        check_result(&[self, exponent_complex], Self::powf(self, exponent))
+    }
+
+    /// Raises this [`Complex`] to a complex power, failing for zero raised to a power with a
+    /// non-positive real part, on overflow or NaN.
+    ///
+    /// Zero raised to a power with a positive real part is zero, and anything raised to the power
+    /// of zero is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::{Complex, ComplexError};
+    ///
+    /// let zero = Complex::new(0.0, 0.0);
+    ///
+    /// assert_eq!(zero.checked_powc(Complex::new(2.0, 1.0)), Ok(zero));
+    /// assert_eq!(zero.checked_powc(Complex::new(-1.0, 1.0)), Err(ComplexError::Pole));
+    /// ```
+    pub fn checked_powc(self, exponent: Self) -> Result<Self, ComplexError> {
+        check_arguments(&[self, exponent])?;
+        if exponent.is_zero() {
+            return Ok(Self::new(T::one(), T::zero()));
+        }
+        if self.is_zero() {
+            return if exponent.real > T::zero() {
+                Ok(Self::zero())
+            } else {
+                Err(ComplexError::Pole)
+            };
+        }
+        check_result(&[self, exponent], Self::powc(self, exponent))
+    }
+
+    /// Returns the principal square root of this [`Complex`], failing on NaN.
+    pub fn checked_sqrt(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[], Self::sqrt)
+    }
+
+    /// Returns the cotangent of this [`Complex`], failing for zero or NaN.
+    pub fn checked_cot(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[Self::zero()], Self::cot)
+    }
+
+    /// Returns the cosecant of this [`Complex`], failing for zero or NaN.
+    pub fn checked_csc(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[Self::zero()], Self::csc)
+    }
+
+    /// Returns the arctangent of this [`Complex`], failing for ±i or NaN.
+    pub fn checked_arctan(self) -> Result<Self, ComplexError> {
+        let poles = [
+            Self::new(T::zero(), T::one()),
+            Self::new(T::zero(), -T::one()),
+        ];
+        check_unary(self, &poles, Self::arctan)
+    }
+
+    /// Returns the hyperbolic cotangent of this [`Complex`], failing for zero or NaN.
+    pub fn checked_coth(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[Self::zero()], Self::coth)
+    }
+
+    /// Returns the hyperbolic cosecant of this [`Complex`], failing for zero or NaN.
+    pub fn checked_csch(self) -> Result<Self, ComplexError> {
+        check_unary(self, &[Self::zero()], Self::csch)
+    }
+
+    /// Returns the hyperbolic arctangent of this [`Complex`], failing for ±1 or NaN.
+    pub fn checked_arctanh(self) -> Result<Self, ComplexError> {
+        let poles = [
+            Self::new(T::one(), T::zero()),
+            Self::new(-T::one(), T::zero()),
+        ];
+        check_unary(self, &poles, Self::arctanh)
+    }
+}
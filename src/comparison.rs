@@ -0,0 +1,166 @@
+// Approximate comparisons, for results that are only expected to be close to a known value
+use crate::Complex;
+use core::fmt::Debug;
+use num_traits::Float;
+
+impl<T: Float> Complex<T> {
+    /// Returns true if this [`Complex`] is within `abs_tol` of `other`, or within `rel_tol` times
+    /// the larger of their absolute values.
+    ///
+    /// Equal numbers, including equal infinities, are always approximately equal, and NaN is
+    /// never approximately equal to anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(0.1, 0.2) + Complex::new(0.2, 0.1);
+    ///
+    /// assert_ne!(z, Complex::new(0.3, 0.3));
+    /// assert!(z.approx_eq(Complex::new(0.3, 0.3), 0.0, 1e-15));
+    /// assert!(z.approx_eq(Complex::new(0.3, 0.3), 1e-15, 0.0));
+    /// ```
+    pub fn approx_eq(self, other: Self, abs_tol: T, rel_tol: T) -> bool {
+        if self == other {
+            return true;
+        }
+        let difference = Self::abs(self - other);
+        let magnitude = T::max(Self::abs(self), Self::abs(other));
+        // NaN differences fail both comparisons
+        difference <= abs_tol || difference <= rel_tol * magnitude
+    }
+}
+
+/// ULP distances need the bit layout of the float, so they are only implemented for f32 and f64.
+macro_rules! ulp_comparison {
+    ($float:ident, $signed:ident) => {
+        impl Complex<$float> {
+            /// Returns the larger of the distances between the real parts and the imaginary parts
+            /// in units in the last place, or `u64::MAX` if any part is NaN.
+            ///
+            /// Adjacent floats are one ulp apart, and the two zeros are zero ulps apart.
+            pub fn ulp_distance(self, other: Self) -> u64 {
+                // Maps the bits onto integers ordered like the floats, with both zeros on 0
+                let ordered = |value: $float| {
+                    let bits = value.to_bits() as $signed;
+                    if bits < 0 { $signed::MIN - bits } else { bits }
+                };
+                let distance = |a: $float, b: $float| {
+                    if a.is_nan() || b.is_nan() {
+                        u64::MAX
+                    } else {
+                        ordered(a).abs_diff(ordered(b)) as u64
+                    }
+                };
+                u64::max(
+                    distance(self.real, other.real),
+                    distance(self.imag, other.imag),
+                )
+            }
+
+            /// Returns true if both parts are at most `max_ulps` units in the last place apart.
+            pub fn ulps_eq(self, other: Self, max_ulps: u64) -> bool {
+                Self::ulp_distance(self, other) <= max_ulps
+            }
+        }
+    };
+}
+
+ulp_comparison!(f32, i32);
+ulp_comparison!(f64, i64);
+
+/// Backs [`assert_complex_eq!`], the tolerance defaults to four times the machine epsilon.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_complex_eq_impl<T: Float + Debug>(
+    left: Complex<T>,
+    right: Complex<T>,
+    tolerance: Option<T>,
+) {
+    let tolerance = tolerance.unwrap_or_else(default_tolerance);
+    if !Complex::approx_eq(left, right, tolerance, T::zero()) {
+        panic!(
+            "assertion `left ≈ right` failed\n     left: {:?}\n    right: {:?}\n distance: {:?}\ntolerance: {:?}",
+            left,
+            right,
+            Complex::abs(left - right),
+            tolerance
+        );
+    }
+}
+
+/// Backs [`assert_complex_relative_eq!`], the tolerance defaults to four times the machine
+/// epsilon.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_complex_relative_eq_impl<T: Float + Debug>(
+    left: Complex<T>,
+    right: Complex<T>,
+    tolerance: Option<T>,
+) {
+    let tolerance = tolerance.unwrap_or_else(default_tolerance);
+    if !Complex::approx_eq(left, right, T::zero(), tolerance) {
+        let magnitude = T::max(Complex::abs(left), Complex::abs(right));
+        panic!(
+            "assertion `left ≈ right` failed\n     left: {:?}\n    right: {:?}\n distance: {:?} (relative)\ntolerance: {:?}",
+            left,
+            right,
+            Complex::abs(left - right) / magnitude,
+            tolerance
+        );
+    }
+}
+
+fn default_tolerance<T: Float>() -> T {
+    T::epsilon() * T::from(4).unwrap()
+}
+
+/// Asserts that two [`Complex`] numbers are at most an absolute tolerance apart.
+///
+/// The tolerance defaults to four times the machine epsilon.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::{Complex, assert_complex_eq};
+///
+/// let z = Complex::new(0.1, 0.2) + Complex::new(0.2, 0.1);
+///
+/// assert_complex_eq!(z, Complex::new(0.3, 0.3));
+/// assert_complex_eq!(z, Complex::new(0.3001, 0.3), 1e-3);
+/// ```
+#[macro_export]
+macro_rules! assert_complex_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_complex_eq_impl($left, $right, None)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        $crate::assert_complex_eq_impl($left, $right, Some($tolerance))
+    };
+}
+
+/// Asserts that two [`Complex`] numbers are at most a relative tolerance apart, relative to the
+/// larger of their absolute values.
+///
+/// The tolerance defaults to four times the machine epsilon.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::{Complex, assert_complex_relative_eq};
+///
+/// let z = Complex::new(3.0, 4.0).exp();
+///
+/// assert_complex_relative_eq!(z, Complex::new(-13.128783081462158, -15.200784463067954));
+/// assert_complex_relative_eq!(z, Complex::new(-13.1, -15.2), 1e-2);
+/// ```
+#[macro_export]
+macro_rules! assert_complex_relative_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_complex_relative_eq_impl($left, $right, None)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        $crate::assert_complex_relative_eq_impl($left, $right, Some($tolerance))
+    };
+}
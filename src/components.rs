@@ -0,0 +1,179 @@
+// Classification of complex numbers and component-wise utilities
+use crate::Complex;
+use core::num::FpCategory;
+use num_traits::Float;
+
+impl<T: Float> Complex<T> {
+    /// Returns true if a part is NaN and neither part is infinite.
+    ///
+    /// Like in Annex G of C99, a number with an infinite part is an infinity even if its other
+    /// part is NaN.
+    pub fn is_nan(self) -> bool {
+        (self.real.is_nan() || self.imag.is_nan()) && !self.is_infinite()
+    }
+
+    /// Returns true if either part is infinite, even if the other part is NaN.
+    pub fn is_infinite(self) -> bool {
+        self.real.is_infinite() || self.imag.is_infinite()
+    }
+
+    /// Returns true if both parts are finite.
+    pub fn is_finite(self) -> bool {
+        self.real.is_finite() && self.imag.is_finite()
+    }
+
+    /// Returns true if this [`Complex`] is finite and not zero, and neither part is subnormal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// assert!(Complex::new(3.0, 0.0).is_normal());
+    /// assert!(!Complex::new(0.0, 0.0).is_normal());
+    /// assert!(!Complex::new(1.0, 1e-310).is_normal());
+    /// ```
+    pub fn is_normal(self) -> bool {
+        self.classify() == FpCategory::Normal
+    }
+
+    /// Returns true if the imaginary part is zero.
+    pub fn is_real(self) -> bool {
+        self.imag.is_zero()
+    }
+
+    /// Returns true if the real part is zero, so zero is both real and imaginary.
+    pub fn is_imaginary(self) -> bool {
+        self.real.is_zero()
+    }
+
+    /// Returns the floating point category of this [`Complex`].
+    ///
+    /// Infinities come before NaN, like in [`Complex::is_infinite`], then NaN, zero if both parts
+    /// are zero, and subnormal if either part is subnormal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    /// use std::num::FpCategory;
+    ///
+    /// assert_eq!(Complex::new(f64::INFINITY, f64::NAN).classify(), FpCategory::Infinite);
+    /// assert_eq!(Complex::new(1.0, f64::NAN).classify(), FpCategory::Nan);
+    /// assert_eq!(Complex::new(1.0, 0.0).classify(), FpCategory::Normal);
+    /// ```
+    pub fn classify(self) -> FpCategory {
+        let categories = (self.real.classify(), self.imag.classify());
+        match categories {
+            (FpCategory::Infinite, _) | (_, FpCategory::Infinite) => FpCategory::Infinite,
+            (FpCategory::Nan, _) | (_, FpCategory::Nan) => FpCategory::Nan,
+            (FpCategory::Zero, FpCategory::Zero) => FpCategory::Zero,
+            (FpCategory::Subnormal, _) | (_, FpCategory::Subnormal) => FpCategory::Subnormal,
+            _ => FpCategory::Normal,
+        }
+    }
+
+    /// Returns the projection of this [`Complex`] onto the Riemann sphere.
+    ///
+    /// Every infinity, including those with a NaN part, is mapped onto inf + 0i with the sign of
+    /// the imaginary part kept on the zero. Other numbers are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(f64::NEG_INFINITY, -2.0).proj();
+    ///
+    /// assert_eq!(z, Complex::new(f64::INFINITY, 0.0));
+    /// assert!(z.imag().is_sign_negative());
+    /// assert_eq!(Complex::new(3.0, 4.0).proj(), Complex::new(3.0, 4.0));
+    /// ```
+    pub fn proj(self) -> Self {
+        if self.is_infinite() {
+            Self::new(T::infinity(), T::zero().copysign(self.imag))
+        } else {
+            self
+        }
+    }
+
+    /// Rounds both parts down.
+    pub fn floor(self) -> Self {
+        Self::new(self.real.floor(), self.imag.floor())
+    }
+
+    /// Rounds both parts up.
+    pub fn ceil(self) -> Self {
+        Self::new(self.real.ceil(), self.imag.ceil())
+    }
+
+    /// Rounds both parts to the nearest integer, rounding half-way cases away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// assert_eq!(Complex::new(2.5, -1.4).round(), Complex::new(3.0, -1.0));
+    /// ```
+    pub fn round(self) -> Self {
+        Self::new(self.real.round(), self.imag.round())
+    }
+
+    /// Rounds both parts towards zero.
+    pub fn trunc(self) -> Self {
+        Self::new(self.real.trunc(), self.imag.trunc())
+    }
+
+    /// Returns the fractional parts, so `z.trunc() + z.fract() == z` for finite z.
+    pub fn fract(self) -> Self {
+        Self::new(self.real.fract(), self.imag.fract())
+    }
+
+    /// Returns the taxicab norm |a| + |b| of this [`Complex`].
+    ///
+    /// Infinities have an infinite norm, even with a NaN part.
+    pub fn l1_norm(self) -> T {
+        if self.is_infinite() {
+            T::infinity()
+        } else {
+            self.real.abs() + self.imag.abs()
+        }
+    }
+
+    /// Returns the maximum norm max(|a|, |b|) of this [`Complex`].
+    ///
+    /// Infinities have an infinite norm, even with a NaN part, and other numbers with a NaN part
+    /// have a NaN norm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(3.0, -4.0);
+    ///
+    /// assert_eq!(z.l1_norm(), 7.0);
+    /// assert_eq!(z.max_norm(), 4.0);
+    /// assert!(Complex::new(1.0, f64::NAN).max_norm().is_nan());
+    /// ```
+    pub fn max_norm(self) -> T {
+        if self.is_infinite() {
+            T::infinity()
+        } else if self.is_nan() {
+            T::nan()
+        } else {
+            self.real.abs().max(self.imag.abs())
+        }
+    }
+
+    /// Multiplies both parts by a real factor.
+    pub fn scale(self, factor: T) -> Self {
+        self * factor
+    }
+
+    /// Divides both parts by a real factor.
+    pub fn unscale(self, factor: T) -> Self {
+        self / factor
+    }
+}
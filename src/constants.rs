@@ -0,0 +1,73 @@
+// Named constants and the c! literal macro, usable in const and static items
+use crate::Complex;
+use num_traits::{ConstOne, ConstZero};
+
+macro_rules! complex_constants {
+    ($float:ident) => {
+        impl Complex<$float> {
+            /// Zero, 0 + 0i, the same as [`ConstZero::ZERO`] without importing the trait
+            pub const ZERO: Self = <Self as ConstZero>::ZERO;
+            /// One, 1 + 0i, the same as [`ConstOne::ONE`] without importing the trait
+            pub const ONE: Self = <Self as ConstOne>::ONE;
+            /// The imaginary unit, 0 + 1i
+            pub const I: Self = Self::new(0.0, 1.0);
+            /// The negative imaginary unit, 0 - 1i
+            pub const NEG_I: Self = Self::new(0.0, -1.0);
+            /// Not a number, NaN + NaN i
+            pub const NAN: Self = Self::new($float::NAN, $float::NAN);
+            /// Positive real infinity, inf + 0i
+            pub const INFINITY: Self = Self::new($float::INFINITY, 0.0);
+        }
+    };
+}
+
+complex_constants!(f32);
+complex_constants!(f64);
+
+/// Creates a [`Complex`] from a literal written like `3.0 + 4.0 i`, as a const expression.
+///
+/// The parts have to be float literals, and the `i` is separated from the imaginary part by a
+/// space. The real or the imaginary part can be left out.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::{Complex, c};
+///
+/// static TWIDDLES: [Complex<f64>; 4] = [c!(1.0), c!(-1.0 i), c!(-1.0), c!(i)];
+/// const DOWN: Complex<f32> = c!(-i);
+///
+/// assert_eq!(c!(3.0 + 4.0 i), Complex::new(3.0, 4.0));
+/// assert_eq!(c!(-1.5 - 2.0 i), Complex::new(-1.5, -2.0));
+/// assert_eq!(c!(2.0 - i), Complex::new(2.0, -1.0));
+/// assert_eq!(TWIDDLES[1], Complex::new(0.0, -1.0));
+/// assert_eq!(DOWN, Complex::new(0.0, -1.0));
+/// ```
+#[macro_export]
+macro_rules! c {
+    // these come first, the literal matcher cannot backtrack out of a lone minus sign
+    (i) => {
+        $crate::Complex::new(0.0, 1.0)
+    };
+    (-i) => {
+        $crate::Complex::new(0.0, -1.0)
+    };
+    ($real:literal + $imag:literal i) => {
+        $crate::Complex::new($real, $imag)
+    };
+    ($real:literal - $imag:literal i) => {
+        $crate::Complex::new($real, -$imag)
+    };
+    ($real:literal + i) => {
+        $crate::Complex::new($real, 1.0)
+    };
+    ($real:literal - i) => {
+        $crate::Complex::new($real, -1.0)
+    };
+    ($imag:literal i) => {
+        $crate::Complex::new(0.0, $imag)
+    };
+    ($real:literal) => {
+        $crate::Complex::new($real, 0.0)
+    };
+}
@@ -0,0 +1,30 @@
+use crate::Complex;
+use num_traits::Float;
+
+/// T -> Complex<T>
+impl<T: Float> From<T> for Complex<T> {
+    fn from(real: T) -> Complex<T> {
+        Complex::new(real, T::zero())
+    }
+}
+
+/// (T, T) -> Complex<T>
+impl<T: Float> From<(T, T)> for Complex<T> {
+    fn from((real, imag): (T, T)) -> Complex<T> {
+        Complex::new(real, imag)
+    }
+}
+
+/// [T; 2] -> Complex<T>
+impl<T: Float> From<[T; 2]> for Complex<T> {
+    fn from([real, imag]: [T; 2]) -> Complex<T> {
+        Complex::new(real, imag)
+    }
+}
+
+/// Complex<f32> -> Complex<f64>, every f32 is exactly representable as an f64
+impl From<Complex<f32>> for Complex<f64> {
+    fn from(z: Complex<f32>) -> Complex<f64> {
+        Complex::new(f64::from(z.real), f64::from(z.imag))
+    }
+}
@@ -0,0 +1,430 @@
+//! Fixed point complex numbers for targets without a floating point unit.
+//!
+//! A [`FixedComplex`] stores both parts as signed fractions in [-1, 1) in one of the Q formats
+//! [`Q15`] and [`Q31`], and only uses integer arithmetic, apart from the conversions from and to
+//! [`Complex<f32>`]. Angles are binary angles in the same format, in units of π, so the whole
+//! range of the raw integer covers one turn and wraps around like an angle does.
+use crate::Complex;
+use crate::overloading::forward_copy_ops;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Q format of the parts of a [`FixedComplex`], implemented by [`Q15`] and [`Q31`].
+pub trait QFormat: sealed::Sealed + Copy + Debug + PartialEq + Eq + Hash + Default {
+    /// The integer holding a part.
+    type Raw: Copy + Debug + PartialEq + Eq + Hash + Default + Ord;
+    /// The number of bits after the binary point.
+    const FRACTIONAL_BITS: u32;
+
+    /// Widens a part to an `i64` with the same value.
+    fn widen(raw: Self::Raw) -> i64;
+    /// Narrows an `i64` to a part, saturating on overflow.
+    fn saturate(value: i64) -> Self::Raw;
+    /// Narrows an `i64` to a part, wrapping on overflow.
+    fn wrap(value: i64) -> Self::Raw;
+    /// Returns a b + c d, rounded to the nearest part and saturated.
+    fn product_sum(a: Self::Raw, b: Self::Raw, c: Self::Raw, d: Self::Raw) -> Self::Raw;
+    /// Returns a b - c d, rounded to the nearest part and saturated.
+    fn product_difference(a: Self::Raw, b: Self::Raw, c: Self::Raw, d: Self::Raw) -> Self::Raw;
+}
+
+/// Implements [`QFormat`] for a format with its part and accumulator types.
+///
+/// The products are accumulated at full precision, saturating only if both of them are (-1)², and
+/// then rounded half up to the part.
+macro_rules! q_format {
+    ($(#[$attr:meta])* $Format:ident, $Raw:ty, $Accumulator:ty, $bits:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+        pub struct $Format;
+
+        impl sealed::Sealed for $Format {}
+
+        impl QFormat for $Format {
+            type Raw = $Raw;
+            const FRACTIONAL_BITS: u32 = $bits;
+
+            fn widen(raw: $Raw) -> i64 {
+                raw as i64
+            }
+
+            fn saturate(value: i64) -> $Raw {
+                value.clamp(<$Raw>::MIN as i64, <$Raw>::MAX as i64) as $Raw
+            }
+
+            fn wrap(value: i64) -> $Raw {
+                value as $Raw
+            }
+
+            fn product_sum(a: $Raw, b: $Raw, c: $Raw, d: $Raw) -> $Raw {
+                let sum = (a as $Accumulator * b as $Accumulator)
+                    .saturating_add(c as $Accumulator * d as $Accumulator);
+                Self::saturate(round_shift(sum as i64, $bits))
+            }
+
+            fn product_difference(a: $Raw, b: $Raw, c: $Raw, d: $Raw) -> $Raw {
+                let difference = (a as $Accumulator * b as $Accumulator)
+                    .saturating_sub(c as $Accumulator * d as $Accumulator);
+                Self::saturate(round_shift(difference as i64, $bits))
+            }
+        }
+    };
+}
+
+q_format!(
+    /// Q1.15, parts in `i16` with 15 fractional bits and products accumulated in `i32`.
+    Q15, i16, i32, 15
+);
+q_format!(
+    /// Q1.31, parts in `i32` with 31 fractional bits and products accumulated in `i64`.
+    Q31, i32, i64, 31
+);
+
+/// Shifts right by some bits, rounding half up.
+///
+/// The half is added with saturation, a value that is already the largest `i64` can only saturate
+/// the part anyway.
+fn round_shift(value: i64, bits: u32) -> i64 {
+    if bits == 0 {
+        value
+    } else {
+        value.saturating_add(1 << (bits - 1)) >> bits
+    }
+}
+
+/// Complex number with fixed point parts in the Q format `Q`.
+///
+/// `+`, `-` and unary `-` saturate, `*` rounds to the nearest value and saturates, which only
+/// happens for products of magnitude 1 or more. The wrapping variants are methods.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::Complex;
+/// use ccmath::fixed::ComplexQ15;
+///
+/// let z = ComplexQ15::from(Complex::new(0.5, -0.25));
+/// let w = ComplexQ15::from(Complex::new(0.5, 0.5));
+///
+/// assert_eq!(z.raw(), (16384, -8192));
+/// assert_eq!(Complex::from(z * w), Complex::new(0.375, 0.125));
+/// assert_eq!((w + w).raw(), (i16::MAX, i16::MAX));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct FixedComplex<Q: QFormat> {
+    real: Q::Raw,
+    imag: Q::Raw,
+    format: PhantomData<Q>,
+}
+
+/// Alias for a [`FixedComplex`] in the [`Q15`] format
+pub type ComplexQ15 = FixedComplex<Q15>;
+/// Alias for a [`FixedComplex`] in the [`Q31`] format
+pub type ComplexQ31 = FixedComplex<Q31>;
+
+/// Arctangents of 2^-i in units of π, scaled by 2³¹, for the CORDIC iterations.
+const ATAN_TABLE: [i64; 31] = [
+    536870912, 316933406, 167458907, 85004756, 42667331, 21354465, 10679838, 5340245, 2670163,
+    1335087, 667544, 333772, 166886, 83443, 41722, 20861, 10430, 5215, 2608, 1304, 652, 326, 163,
+    81, 41, 20, 10, 5, 3, 1, 1,
+];
+
+/// The inverse of the CORDIC gain, ∏ 1 / √(1 + 4^-i), scaled by 2³¹.
+const CORDIC_SCALE: i64 = 1304065748;
+
+/// Half a turn, π, scaled by 2³¹.
+const HALF_TURN: i64 = 1 << 31;
+
+impl<Q: QFormat> FixedComplex<Q> {
+    /// Creates a new [`FixedComplex`] from its raw parts.
+    pub const fn from_raw(real: Q::Raw, imag: Q::Raw) -> Self {
+        Self {
+            real,
+            imag,
+            format: PhantomData,
+        }
+    }
+
+    /// Returns the raw real part of this [`FixedComplex`].
+    pub fn real(self) -> Q::Raw {
+        self.real
+    }
+
+    /// Returns the raw imaginary part of this [`FixedComplex`].
+    pub fn imag(self) -> Q::Raw {
+        self.imag
+    }
+
+    /// Returns both raw parts of this [`FixedComplex`].
+    pub fn raw(self) -> (Q::Raw, Q::Raw) {
+        (self.real, self.imag)
+    }
+
+    /// Creates a new [`FixedComplex`] from two parts widened to `i64`, saturating both.
+    fn saturating_from_wide(real: i64, imag: i64) -> Self {
+        Self::from_raw(Q::saturate(real), Q::saturate(imag))
+    }
+
+    /// Adds both parts, saturating on overflow.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturating_from_wide(
+            Q::widen(self.real) + Q::widen(rhs.real),
+            Q::widen(self.imag) + Q::widen(rhs.imag),
+        )
+    }
+
+    /// Adds both parts, wrapping around on overflow.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::from_raw(
+            Q::wrap(Q::widen(self.real) + Q::widen(rhs.real)),
+            Q::wrap(Q::widen(self.imag) + Q::widen(rhs.imag)),
+        )
+    }
+
+    /// Subtracts both parts, saturating on overflow.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::saturating_from_wide(
+            Q::widen(self.real) - Q::widen(rhs.real),
+            Q::widen(self.imag) - Q::widen(rhs.imag),
+        )
+    }
+
+    /// Subtracts both parts, wrapping around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::fixed::ComplexQ15;
+    ///
+    /// let z = ComplexQ15::from_raw(-32000, 100);
+    /// let w = ComplexQ15::from_raw(1000, -100);
+    ///
+    /// assert_eq!(z.saturating_sub(w).raw(), (-32768, 200));
+    /// assert_eq!(z.wrapping_sub(w).raw(), (32536, 200));
+    /// ```
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::from_raw(
+            Q::wrap(Q::widen(self.real) - Q::widen(rhs.real)),
+            Q::wrap(Q::widen(self.imag) - Q::widen(rhs.imag)),
+        )
+    }
+
+    /// Returns the complex conjugate of this [`FixedComplex`], saturating -(-1) to the largest
+    /// part.
+    pub fn conj(self) -> Self {
+        Self::saturating_from_wide(Q::widen(self.real), -Q::widen(self.imag))
+    }
+
+    /// Multiplies both parts by a raw real factor, rounding to the nearest value.
+    pub fn scale(self, factor: Q::Raw) -> Self {
+        let zero = Q::Raw::default();
+        Self::from_raw(
+            Q::product_sum(self.real, factor, zero, zero),
+            Q::product_sum(self.imag, factor, zero, zero),
+        )
+    }
+
+    /// Returns an approximation of the absolute value of this [`FixedComplex`] with the
+    /// alpha max plus beta min algorithm.
+    ///
+    /// With α = 15/16 and β = 15/32 this needs only shifts and adds and is at most 6.25% off.
+    /// Magnitudes of 1 or more saturate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    /// use ccmath::fixed::ComplexQ15;
+    ///
+    /// let z = ComplexQ15::from(Complex::new(0.3, -0.4));
+    /// let magnitude = z.approx_abs() as f32 / 32768.0;
+    ///
+    /// assert!((magnitude - 0.5).abs() < 0.5 * 0.0625);
+    /// ```
+    pub fn approx_abs(self) -> Q::Raw {
+        let (real, imag) = (Q::widen(self.real).abs(), Q::widen(self.imag).abs());
+        let (max, min) = (real.max(imag), real.min(imag));
+        Q::saturate(max - (max >> 4) + (min >> 1) - (min >> 5))
+    }
+
+    /// Rotates this [`FixedComplex`] by a binary angle in units of π, with CORDIC.
+    ///
+    /// The rotation only uses shifts and adds, and the CORDIC gain is divided out beforehand, so
+    /// the absolute value is kept up to a few units in the last place. Results of magnitude 1 or
+    /// more saturate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::{Complex, assert_complex_eq};
+    /// use ccmath::fixed::ComplexQ31;
+    ///
+    /// let z = ComplexQ31::from(Complex::new(0.5, 0.0));
+    /// let quarter_turn = ComplexQ31::angle_from_radians(std::f32::consts::FRAC_PI_2);
+    ///
+    /// assert_complex_eq!(Complex::from(z.rotate(quarter_turn)), Complex::new(0.0, 0.5), 1e-6);
+    /// ```
+    pub fn rotate(self, angle: Q::Raw) -> Self {
+        let shift = 31 - Q::FRACTIONAL_BITS;
+        let mut x = Q::widen(self.real) << shift;
+        let mut y = Q::widen(self.imag) << shift;
+        let mut angle = Q::widen(angle) << shift;
+        // CORDIC converges for angles up to about 99°, so larger rotations start with a half turn
+        if angle.abs() > HALF_TURN / 2 {
+            (x, y) = (-x, -y);
+            angle -= HALF_TURN * angle.signum();
+        }
+        x = round_shift(x * CORDIC_SCALE, 31);
+        y = round_shift(y * CORDIC_SCALE, 31);
+        for (i, &step) in ATAN_TABLE.iter().enumerate().take(cordic_steps::<Q>()) {
+            let (dx, dy) = (y >> i, x >> i);
+            if angle >= 0 {
+                (x, y) = (x - dx, y + dy);
+                angle -= step;
+            } else {
+                (x, y) = (x + dx, y - dy);
+                angle += step;
+            }
+        }
+        Self::saturating_from_wide(round_shift(x, shift), round_shift(y, shift))
+    }
+
+    /// Returns the argument of this [`FixedComplex`] as a binary angle in units of π, with CORDIC.
+    ///
+    /// The angle is in [-1, 1), with π saturating to the largest part, and the argument of zero is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    /// use ccmath::fixed::ComplexQ15;
+    ///
+    /// let z = ComplexQ15::from(Complex::new(-0.5, 0.5));
+    ///
+    /// assert_eq!(z.arg(), 24576);
+    /// assert!((ComplexQ15::angle_to_radians(z.arg()) - 3.0 * std::f32::consts::FRAC_PI_4).abs() < 1e-4);
+    /// ```
+    pub fn arg(self) -> Q::Raw {
+        let shift = 31 - Q::FRACTIONAL_BITS;
+        let mut x = Q::widen(self.real) << shift;
+        let mut y = Q::widen(self.imag) << shift;
+        let mut angle = 0;
+        // vectoring only converges in the right half-plane, so the left one is turned around
+        if x < 0 {
+            angle = if y >= 0 { HALF_TURN } else { -HALF_TURN };
+            (x, y) = (-x, -y);
+        }
+        for (i, &step) in ATAN_TABLE.iter().enumerate().take(cordic_steps::<Q>()) {
+            let (dx, dy) = (y >> i, x >> i);
+            if y > 0 {
+                (x, y) = (x + dx, y - dy);
+                angle += step;
+            } else if y < 0 {
+                (x, y) = (x - dx, y + dy);
+                angle -= step;
+            }
+        }
+        Q::saturate(round_shift(angle, shift))
+    }
+
+    /// Converts an angle in radians to a binary angle in units of π, wrapping around at ±π.
+    pub fn angle_from_radians(radians: f32) -> Q::Raw {
+        let turns = radians as f64 / core::f64::consts::PI;
+        Q::wrap(round_to_i64(turns * (1u64 << Q::FRACTIONAL_BITS) as f64))
+    }
+
+    /// Converts a binary angle in units of π to radians.
+    pub fn angle_to_radians(angle: Q::Raw) -> f32 {
+        (Q::widen(angle) as f64 / (1u64 << Q::FRACTIONAL_BITS) as f64 * core::f64::consts::PI)
+            as f32
+    }
+}
+
+/// Returns the number of CORDIC iterations that still change a result in the format `Q`.
+fn cordic_steps<Q: QFormat>() -> usize {
+    (Q::FRACTIONAL_BITS as usize + 1).min(ATAN_TABLE.len())
+}
+
+/// Rounds to the nearest integer, halves away from zero, saturating and turning NaN into zero.
+fn round_to_i64(value: f64) -> i64 {
+    if value < 0.0 {
+        (value - 0.5) as i64
+    } else {
+        (value + 0.5) as i64
+    }
+}
+
+// Operators
+
+/// FixedComplex + FixedComplex, saturating
+impl<Q: QFormat> Add for FixedComplex<Q> {
+    type Output = FixedComplex<Q>;
+
+    fn add(self, rhs: FixedComplex<Q>) -> FixedComplex<Q> {
+        self.saturating_add(rhs)
+    }
+}
+
+/// FixedComplex - FixedComplex, saturating
+impl<Q: QFormat> Sub for FixedComplex<Q> {
+    type Output = FixedComplex<Q>;
+
+    fn sub(self, rhs: FixedComplex<Q>) -> FixedComplex<Q> {
+        self.saturating_sub(rhs)
+    }
+}
+
+/// FixedComplex * FixedComplex, with both products of each part accumulated before rounding
+impl<Q: QFormat> Mul for FixedComplex<Q> {
+    type Output = FixedComplex<Q>;
+
+    fn mul(self, rhs: FixedComplex<Q>) -> FixedComplex<Q> {
+        let (a, b, c, d) = (self.real, self.imag, rhs.real, rhs.imag);
+        FixedComplex::from_raw(
+            Q::product_difference(a, c, b, d),
+            Q::product_sum(a, d, b, c),
+        )
+    }
+}
+
+forward_copy_ops!(impl[Q: QFormat] FixedComplex<Q>:
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+);
+
+/// -FixedComplex, saturating
+impl<Q: QFormat> Neg for FixedComplex<Q> {
+    type Output = FixedComplex<Q>;
+
+    fn neg(self) -> FixedComplex<Q> {
+        FixedComplex::saturating_from_wide(-Q::widen(self.real), -Q::widen(self.imag))
+    }
+}
+
+// Conversions
+
+/// Complex<f32> -> FixedComplex, rounding to the nearest value and saturating outside [-1, 1),
+/// with NaN parts becoming zero
+impl<Q: QFormat> From<Complex<f32>> for FixedComplex<Q> {
+    fn from(z: Complex<f32>) -> Self {
+        let to_part = |part: f32| round_to_i64(part as f64 * (1u64 << Q::FRACTIONAL_BITS) as f64);
+        Self::saturating_from_wide(to_part(z.real), to_part(z.imag))
+    }
+}
+
+/// FixedComplex -> Complex<f32>, rounding the Q31 parts to the nearest float
+impl<Q: QFormat> From<FixedComplex<Q>> for Complex<f32> {
+    fn from(z: FixedComplex<Q>) -> Complex<f32> {
+        let to_float =
+            |part: Q::Raw| (Q::widen(part) as f64 / (1u64 << Q::FRACTIONAL_BITS) as f64) as f32;
+        Complex::new(to_float(z.real), to_float(z.imag))
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{Complex, Polar};
+use core::fmt::{Alignment, Display, Formatter, LowerExp, Result, UpperExp, Write};
+use num_traits::Float;
+
+/// Writes a number with an optional plus sign and precision, using the given format type.
+macro_rules! write_number {
+    ($w:expr, $value:expr, $plus:expr, $precision:expr, $spec:literal) => {
+        match ($plus, $precision) {
+            (false, None) => write!($w, concat!("{:", $spec, "}"), $value),
+            (true, None) => write!($w, concat!("{:+", $spec, "}"), $value),
+            (false, Some(precision)) => write!($w, concat!("{:.*", $spec, "}"), precision, $value),
+            (true, Some(precision)) => write!($w, concat!("{:+.*", $spec, "}"), precision, $value),
+        }
+    };
+}
+
+/// Counts the characters written to it, used to work out the padding.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// The way a single part is written: the value, whether to force a plus sign, and the precision.
+type PartWriter<'a, T> = &'a dyn Fn(&mut dyn Write, T, bool, Option<usize>) -> Result;
+
+/// Writes `a + bi` while honouring the flags of the formatter.
+///
+/// The precision applies to both parts, the plus flag to the real part, the width and alignment
+/// to the number as a whole, and the alternate flag swaps the i for a j. The `0` flag pads with
+/// zeros after the sign of the real part, like it does for a float, and ignores the alignment.
+fn fmt_complex<T: Float>(
+    z: &Complex<T>,
+    f: &mut Formatter<'_>,
+    write_part: PartWriter<T>,
+) -> Result {
+    let plus = f.sign_plus();
+    let precision = f.precision();
+    let unit = if f.alternate() { "j" } else { "i" };
+    // The sign bit is used so -0 still shows up as a subtraction
+    let operator = if z.imag.is_sign_negative() { "-" } else { "+" };
+
+    let write_imag = |w: &mut dyn Write| -> Result {
+        write!(w, " {} ", operator)?;
+        write_part(w, T::abs(z.imag), false, precision)?;
+        w.write_str(unit)
+    };
+    let write_all = |w: &mut dyn Write| -> Result {
+        write_part(w, z.real, plus, precision)?;
+        write_imag(w)
+    };
+
+    let Some(width) = f.width() else {
+        return write_all(f);
+    };
+
+    let mut counter = CharCounter(0);
+    write_all(&mut counter)?;
+    let padding = width.saturating_sub(counter.0);
+
+    if f.sign_aware_zero_pad() {
+        // NaN is written without a sign, even with the plus flag
+        let sign = if z.real.is_nan() {
+            ""
+        } else if z.real.is_sign_negative() {
+            "-"
+        } else if plus {
+            "+"
+        } else {
+            ""
+        };
+        f.write_str(sign)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        write_part(f, T::abs(z.real), false, precision)?;
+        return write_imag(f);
+    }
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write_all(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+// Implements display
+impl<T: Float + Display> Display for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_complex(self, f, &|w, value, plus, precision| {
+            write_number!(w, value, plus, precision, "")
+        })
+    }
+}
+
+// Implements scientific notation with a lowercase e
+impl<T: Float + LowerExp> LowerExp for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_complex(self, f, &|w, value, plus, precision| {
+            write_number!(w, value, plus, precision, "e")
+        })
+    }
+}
+
+// Implements scientific notation with an uppercase E
+impl<T: Float + UpperExp> UpperExp for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_complex(self, f, &|w, value, plus, precision| {
+            write_number!(w, value, plus, precision, "E")
+        })
+    }
+}
+
+/// Adapter for printing a [`Complex`] in polar form, created by [`Complex::display_polar`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayPolar<T: Float> {
+    polar: Polar<T>,
+    degrees: bool,
+    exponential: bool,
+    precision: Option<usize>,
+}
+
+impl<T: Float> DisplayPolar<T> {
+    /// Prints the angle in degrees instead of radians.
+    pub fn degrees(self) -> Self {
+        Self {
+            degrees: true,
+            ..self
+        }
+    }
+
+    /// Prints `r·e^{iθ}` instead of `r∠θ`.
+    pub fn exponential(self) -> Self {
+        Self {
+            exponential: true,
+            ..self
+        }
+    }
+
+    /// Sets the number of decimals, this takes priority over the precision of the formatter.
+    pub fn precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+impl<T: Float + Display> Display for DisplayPolar<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let precision = self.precision.or(f.precision());
+        let (angle, unit) = if self.degrees {
+            (T::to_degrees(self.polar.angle), "°")
+        } else {
+            (self.polar.angle, "")
+        };
+
+        write_number!(f, self.polar.modulus, false, precision, "")?;
+        if self.exponential {
+            f.write_str("·e^{i")?;
+            write_number!(f, angle, false, precision, "")?;
+            write!(f, "{}}}", unit)
+        } else {
+            f.write_str("∠")?;
+            write_number!(f, angle, false, precision, "")?;
+            f.write_str(unit)
+        }
+    }
+}
+
+/// The markup language a [`DisplayMarkup`] writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Markup {
+    Latex,
+    Typst,
+}
+
+/// Adapter for printing a [`Complex`] as LaTeX or Typst math, created by
+/// [`Complex::display_latex`] and [`Complex::display_typst`].
+///
+/// The output is meant to be placed inside math mode, so it doesn't include any `$` delimiters.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayMarkup<T: Float> {
+    z: Complex<T>,
+    markup: Markup,
+    polar: bool,
+    precision: Option<usize>,
+}
+
+impl<T: Float> DisplayMarkup<T> {
+    /// Writes the number as `r e^(iθ)` instead of `a + bi`.
+    pub fn polar(self) -> Self {
+        Self {
+            polar: true,
+            ..self
+        }
+    }
+
+    /// Sets the number of decimals, this takes priority over the precision of the formatter.
+    pub fn precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Writes a single number, spelling out infinity and NaN in the markup language.
+    ///
+    /// Returns whether the number ended in a word, which needs a space before a following `i`.
+    fn write_number(
+        &self,
+        f: &mut Formatter<'_>,
+        value: T,
+        precision: Option<usize>,
+    ) -> core::result::Result<bool, core::fmt::Error>
+    where
+        T: Display,
+    {
+        if value.is_nan() {
+            f.write_str(match self.markup {
+                Markup::Latex => r"\mathrm{NaN}",
+                Markup::Typst => "\"NaN\"",
+            })?;
+            return Ok(self.markup == Markup::Latex);
+        }
+
+        if value.is_sign_negative() {
+            f.write_str("-")?;
+        }
+        if value.is_infinite() {
+            f.write_str(match self.markup {
+                Markup::Latex => r"\infty",
+                Markup::Typst => "infinity",
+            })?;
+            Ok(true)
+        } else {
+            write_number!(f, T::abs(value), false, precision, "")?;
+            Ok(false)
+        }
+    }
+
+    /// Writes `value` followed by the imaginary unit.
+    fn write_imaginary(&self, f: &mut Formatter<'_>, value: T, precision: Option<usize>) -> Result
+    where
+        T: Display,
+    {
+        if self.write_number(f, value, precision)? {
+            f.write_str(" ")?;
+        }
+        f.write_str("i")
+    }
+}
+
+impl<T: Float + Display> Display for DisplayMarkup<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let precision = self.precision.or(f.precision());
+
+        if self.polar {
+            let polar = Polar::from(self.z);
+            self.write_number(f, polar.modulus, precision)?;
+            f.write_str(match self.markup {
+                Markup::Latex => " e^{",
+                Markup::Typst => " e^(",
+            })?;
+            self.write_imaginary(f, polar.angle, precision)?;
+            f.write_str(match self.markup {
+                Markup::Latex => "}",
+                Markup::Typst => ")",
+            })
+        } else {
+            self.write_number(f, self.z.real, precision)?;
+            let operator = if self.z.imag.is_sign_negative() {
+                " - "
+            } else {
+                " + "
+            };
+            f.write_str(operator)?;
+            self.write_imaginary(f, T::abs(self.z.imag), precision)
+        }
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// Returns an adapter that prints this [`Complex`] in polar form, like `5∠0.9273`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(0.0, 2.0);
+    ///
+    /// assert_eq!(format!("{:.4}", z.display_polar()), "2.0000∠1.5708");
+    /// assert_eq!(z.display_polar().degrees().to_string(), "2∠90°");
+    /// assert_eq!(z.display_polar().exponential().precision(2).to_string(), "2.00·e^{i1.57}");
+    /// ```
+    pub fn display_polar(self) -> DisplayPolar<T> {
+        DisplayPolar {
+            polar: Polar::from(self),
+            degrees: false,
+            exponential: false,
+            precision: None,
+        }
+    }
+
+    /// Returns an adapter that prints this [`Complex`] as LaTeX math.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(f64::NEG_INFINITY, -0.5);
+    /// assert_eq!(z.display_latex().to_string(), r"-\infty - 0.5i");
+    /// ```
+    pub fn display_latex(self) -> DisplayMarkup<T> {
+        DisplayMarkup {
+            z: self,
+            markup: Markup::Latex,
+            polar: false,
+            precision: None,
+        }
+    }
+
+    /// Returns an adapter that prints this [`Complex`] as Typst math.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(1.0, f64::INFINITY);
+    /// assert_eq!(z.display_typst().to_string(), "1 + infinity i");
+    /// ```
+    pub fn display_typst(self) -> DisplayMarkup<T> {
+        DisplayMarkup {
+            z: self,
+            markup: Markup::Typst,
+            polar: false,
+            precision: None,
+        }
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl<T: Float + Display> Complex<T> {
+    /// Returns this [`Complex`] as LaTeX math, see [`Complex::display_latex`] for more options.
+    pub fn to_latex(self) -> String {
+        Complex::display_latex(self).to_string()
+    }
+
+    /// Returns this [`Complex`] as Typst math, see [`Complex::display_typst`] for more options.
+    pub fn to_typst(self) -> String {
+        Complex::display_typst(self).to_string()
+    }
+}
@@ -1,11 +1,28 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 /// CCMath: a crate for doing math with complex numbers
-use num_traits::Float;
-use std::fmt::{Debug, Display, Formatter, Result};
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("ccmath needs either the `std` or the `libm` feature for its floating point math");
+
+use core::ops::Neg;
+use num_traits::{Float, Num};
 
 /// Struct representing a complex number
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Complex<T: Float> {
+///
+/// With the `serde` feature this serializes as `{ "re": ..., "im": ... }`, the `serialization`
+/// module provides the other representations.
+///
+/// Addition, subtraction and negation work for any part type that implements `Num + Clone`.
+/// Multiplication, division and remainder need a [`ComplexPart`], which is only implemented for
+/// the primitive numbers, `Wrapping`, `BigInt` and `BigUint` with the `num-bigint` feature and
+/// `Ratio` with the `num-rational` feature. The floats multiply and divide differently from the
+/// exact types, and the orphan rules keep other crates from implementing it for their own types.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Complex<T> {
+    #[cfg_attr(feature = "serde", serde(rename = "re"))]
     real: T,
+    #[cfg_attr(feature = "serde", serde(rename = "im"))]
     imag: T,
 }
 
@@ -15,6 +32,9 @@ pub type CC<T> = Complex<T>;
 
 trait Numbers: Float {
     fn two() -> Self;
+    fn pi() -> Self;
+    fn cos_m1(self) -> Self;
+    fn cosh_m1(self) -> Self;
 }
 
 impl<T: Float> Numbers for T {
@@ -22,19 +42,80 @@ impl<T: Float> Numbers for T {
     fn two() -> T {
         T::one() + T::one()
     }
+
+    /// Returns the number pi
+    fn pi() -> T {
+        T::acos(-T::one())
+    }
+
+    /// Returns cos(self) - 1 without cancellation for small self
+    fn cos_m1(self) -> T {
+        let sine = T::sin(self / T::two());
+        -T::two() * sine * sine
+    }
+
+    /// Returns cosh(self) - 1 without cancellation for small self
+    fn cosh_m1(self) -> T {
+        let sine = T::sinh(self / T::two());
+        T::two() * sine * sine
+    }
 }
 
-impl<T: Float> Complex<T> {
+impl<T> Complex<T> {
     /// Creates a new [`Complex`].
-    pub fn new(real: T, imag: T) -> Self {
+    pub const fn new(real: T, imag: T) -> Self {
         Self { real, imag }
     }
 
-    /// Returns the imaginary number i
-    pub fn i() -> Self {
-        Self::new(T::zero(), T::one())
+    /// Returns a reference to the real part of this [`Complex`], for parts that aren't Copy.
+    pub fn real_ref(&self) -> &T {
+        &self.real
+    }
+
+    /// Returns a reference to the imaginary part of this [`Complex`], for parts that aren't Copy.
+    pub fn imag_ref(&self) -> &T {
+        &self.imag
+    }
+
+    /// Sets the real part of this [`Complex`].
+    pub fn set_real(&mut self, real: T) {
+        self.real = real;
     }
 
+    /// Sets the imaginary part of this [`Complex`].
+    pub fn set_imag(&mut self, imag: T) {
+        self.imag = imag;
+    }
+
+    /// Returns a mutable reference to the real part of this [`Complex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let mut z = Complex::new(1.0, 2.0);
+    /// *z.real_mut() += 2.0;
+    /// z.set_imag(4.0);
+    ///
+    /// assert_eq!(z, Complex::new(3.0, 4.0));
+    /// ```
+    pub fn real_mut(&mut self) -> &mut T {
+        &mut self.real
+    }
+
+    /// Returns a mutable reference to the imaginary part of this [`Complex`].
+    pub fn imag_mut(&mut self) -> &mut T {
+        &mut self.imag
+    }
+
+    /// Returns mutable references to the real and imaginary parts of this [`Complex`].
+    pub fn parts_mut(&mut self) -> (&mut T, &mut T) {
+        (&mut self.real, &mut self.imag)
+    }
+}
+
+impl<T: Copy> Complex<T> {
     /// Returns the real part of this [`Complex`].
     ///
     /// # Examples
@@ -57,12 +138,35 @@ impl<T: Float> Complex<T> {
     /// use ccmath::Complex;
     ///
     /// let z = Complex::new(-1.4, 21.6);
-    /// assert_eq!(z.real(), -1.4);
+    /// assert_eq!(z.imag(), 21.6);
     /// ```
     pub fn imag(self) -> T {
         self.imag
     }
+}
 
+impl<T: Num + Clone> Complex<T> {
+    /// Returns the imaginary number i
+    pub fn i() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+
+    /// Returns the square of the absolute value of this [`Complex`], computed exactly for integer
+    /// and rational parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// assert_eq!(Complex::new(3, -4).norm(), 25);
+    /// ```
+    pub fn norm(&self) -> T {
+        self.real.clone() * self.real.clone() + self.imag.clone() * self.imag.clone()
+    }
+}
+
+impl<T: Neg<Output = T>> Complex<T> {
     /// Returns the conjugate of this [`Complex`].
     ///
     /// # Examples
@@ -74,11 +178,14 @@ impl<T: Float> Complex<T> {
     /// let z_conjugate = z.conj();
     ///
     /// assert_eq!(z_conjugate, Complex::new(4.0, -5.0));
+    /// assert_eq!(Complex::new(4, 5).conj(), Complex::new(4, -5));
     /// ```
     pub fn conj(self) -> Self {
         Self::new(self.real, -self.imag)
     }
+}
 
+impl<T: Float> Complex<T> {
     /// Returns the square of the absolute value of this [`Complex`].
     ///
     /// # Examples
@@ -110,29 +217,78 @@ impl<T: Float> Complex<T> {
     /// assert_eq!(Complex::abs(z2), f32::sqrt(22.05));
     /// ```
     pub fn abs(self) -> T {
-        T::sqrt(Self::square_abs(self))
+        // hypot avoids squaring the parts, which would overflow or underflow for extreme values
+        T::hypot(self.real, self.imag)
     }
 
-    /// Returns the argument on the interval (-PI, PI] of this [`Complex`].
+    /// Returns the argument on the interval [-PI, PI] of this [`Complex`].
+    ///
+    /// The sign of a zero imaginary part picks the side of the negative real axis, so -1 + 0i has
+    /// argument PI and -1 - 0i has argument -PI.
     pub fn arg(self) -> T {
-        if Self::abs(self) == T::zero() {
-            T::zero()
-        } else {
-            self.imag.signum() * T::acos(self.real / Self::abs(self))
-        }
+        T::atan2(self.imag, self.real)
     }
 
-    /// Returns the square root of this [`Complex`].
+    /// Returns the principal square root of this [`Complex`].
+    ///
+    /// The branch cut lies along the negative real axis, the result always has a non-negative real
+    /// part and an imaginary part with the same sign as the imaginary part of this [`Complex`].
     pub fn sqrt(self) -> Self {
-        Self::new(
-            T::sqrt((self.real + Self::abs(self)) / T::two()),
-            self.imag.signum() * T::sqrt((-self.real + Self::abs(self)) / T::two()),
-        )
+        let (x, y) = (self.real, self.imag);
+        if x == T::zero() && y == T::zero() {
+            return Self::new(T::zero(), y);
+        }
+        if y.is_infinite() {
+            return Self::new(T::infinity(), y);
+        }
+        if x.is_nan() {
+            return Self::new(x, x);
+        }
+        if x.is_infinite() {
+            return if x > T::zero() {
+                Self::new(
+                    x,
+                    if y.is_nan() {
+                        y
+                    } else {
+                        T::copysign(T::zero(), y)
+                    },
+                )
+            } else {
+                Self::new(if y.is_nan() { y } else { T::zero() }, T::copysign(x, y))
+            };
+        }
+        if y.is_nan() {
+            return Self::new(y, y);
+        }
+
+        // Parts near the edges of the exponent range are scaled by an even power of two, so the
+        // sum below doesn't overflow and tiny parts don't lose bits
+        let four = T::two() * T::two();
+        let blowup = T::two() / T::epsilon();
+        let largest = T::max(T::abs(x), T::abs(y));
+        let (x, y, scale) = if largest >= T::max_value() / four {
+            (x / four, y / four, T::two())
+        } else if largest < T::min_positive_value() {
+            (x * blowup * blowup, y * blowup * blowup, T::recip(blowup))
+        } else {
+            (x, y, T::one())
+        };
+
+        let t = T::sqrt((T::abs(x) + T::hypot(x, y)) / T::two());
+        if x >= T::zero() {
+            Self::new(t * scale, y / (T::two() * t) * scale)
+        } else {
+            Self::new(
+                T::abs(y) / (T::two() * t) * scale,
+                T::copysign(t, y) * scale,
+            )
+        }
     }
 
     /// Returns the multiplicative inverse of this [`Complex`].
     pub fn inv(self) -> Self {
-        Self::conj(self) / Self::square_abs(self)
+        overloading::divide(Self::new(T::one(), T::zero()), self)
     }
 
     /// Returns this [`Complex`] raised to a power using exponentiation by squaring.
@@ -145,9 +301,10 @@ impl<T: Float> Complex<T> {
                 if exponent < 0 {
                     Self::inv(Self::powi(self, -exponent))
                 } else if exponent.rem_euclid(2) == 0 {
-                    Self::powi(self * self, exponent / 2)
+                    Self::powi(overloading::multiply(self, self), exponent / 2)
                 } else {
-                    self * Self::powi(self * self, (exponent - 1) / 2)
+                    let square = overloading::multiply(self, self);
+                    overloading::multiply(self, Self::powi(square, (exponent - 1) / 2))
                 }
             }
         }
@@ -161,12 +318,67 @@ impl<T: Float> Complex<T> {
 
     /// Returns this [`Complex`] raised to a complex power.
     pub fn powc(self, exponent: Self) -> Self {
-        Self::powf(self, exponent.real) * Self::exp(Self::ln(self) * Self::i() * exponent.imag)
+        let exponential =
+            Self::exp(overloading::multiply(Self::ln(self), Self::i()) * exponent.imag);
+        overloading::multiply(Self::powf(self, exponent.real), exponential)
     }
 
     /// Returns e raised to the power of this [`Complex`].
     pub fn exp(self) -> Self {
-        Self::new(T::cos(self.imag), T::sin(self.imag)) * T::exp(self.real)
+        let (x, y) = (self.real, self.imag);
+        if x.is_infinite() {
+            if x < T::zero() {
+                // e^(-inf + iy) is a zero in the direction of y
+                return if y.is_finite() {
+                    Self::new(T::zero() * T::cos(y), T::zero() * T::sin(y))
+                } else {
+                    Self::new(T::zero(), T::copysign(T::zero(), y))
+                };
+            }
+            if y == T::zero() {
+                return Self::new(x, y);
+            }
+            if !y.is_finite() {
+                return Self::new(x, T::nan());
+            }
+        }
+        if y == T::zero() {
+            return Self::new(T::exp(x), y);
+        }
+
+        // e^x can overflow while e^x cos(y) and e^x sin(y) don't, so it is applied in two halves
+        if x > T::ln(T::max_value()) {
+            let half = T::exp(x / T::two());
+            return Self::new(half * T::cos(y) * half, half * T::sin(y) * half);
+        }
+        Self::new(T::cos(y), T::sin(y)) * T::exp(x)
+    }
+
+    /// Returns e raised to the power of this [`Complex`], minus one.
+    ///
+    /// This is accurate for small numbers, where `exp(z) - 1` cancels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(1e-20, 1e-20);
+    ///
+    /// assert_eq!(z.exp() - 1.0, Complex::new(0.0, 1e-20));
+    /// assert_eq!(z.exp_m1(), Complex::new(1e-20, 1e-20));
+    /// ```
+    pub fn exp_m1(self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if !x.is_finite() || !y.is_finite() || x > T::ln(T::max_value()) {
+            return Self::exp(self) - T::one();
+        }
+
+        // e^x cos(y) - 1 = (e^x - 1) cos(y) + (cos(y) - 1)
+        Self::new(
+            T::mul_add(T::exp_m1(x), T::cos(y), y.cos_m1()),
+            T::exp(x) * T::sin(y),
+        )
     }
 
     /// Returns base raised to the power of this [`Complex`].
@@ -175,15 +387,67 @@ impl<T: Float> Complex<T> {
     }
 
     /// Returns the natural logarithm of the absolute value of this [`Complex`].
+    ///
+    /// This stays accurate when the absolute value is close to 1.
     pub fn ln_abs(self) -> T {
-        T::ln(Self::square_abs(self)) / T::two()
+        let abs = Self::abs(self);
+        let half = T::one() / T::two();
+        if !(abs > half && abs < T::two()) {
+            return T::ln(abs);
+        }
+
+        // ln|z| = ln(1 + (a - 1)(a + 1) + b^2) / 2, with the rounding error of the product added
+        // back, so nothing cancels when |z| is close to 1. a <= |z| < 2, and a - 1 is exact by
+        // Sterbenz's lemma for a >= 1/2. A smaller a rounds a - 1, but then |z| < 1/√2 and the
+        // sum stays far from zero, so that error isn't amplified
+        let a = T::max(T::abs(self.real), T::abs(self.imag));
+        let b = T::min(T::abs(self.real), T::abs(self.imag));
+        let product = (a - T::one()) * (a + T::one());
+        let product_error = T::mul_add(a - T::one(), a + T::one(), -product);
+        T::ln_1p(T::mul_add(b, b, product) + product_error) / T::two()
     }
 
     /// Returns the natural logarithm of this [`Complex`].
+    ///
+    /// The branch cut lies along the negative real axis, the imaginary part is on [-PI, PI].
     pub fn ln(self) -> Self {
         Self::new(Self::ln_abs(self), Self::arg(self))
     }
 
+    /// Returns the natural logarithm of one plus this [`Complex`].
+    ///
+    /// This is accurate for small numbers, where `(z + 1).ln()` loses the low bits of z.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(1e-20, 0.0);
+    ///
+    /// assert_eq!((z + 1.0).ln(), Complex::new(0.0, 0.0));
+    /// assert_eq!(z.ln_1p(), Complex::new(1e-20, 0.0));
+    /// ```
+    pub fn ln_1p(self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        let half = T::one() / T::two();
+        if !(T::abs(x) < half && T::abs(y) < half) {
+            return Self::ln(self + T::one());
+        }
+
+        // |1 + z|^2 - 1 = x (2 + x) + y^2, with the rounding errors of 2 + x and of the product
+        // added back
+        let sum = T::two() + x;
+        let sum_error = (T::two() - sum) + x;
+        let product = x * sum;
+        let product_error = T::mul_add(x, sum, -product);
+        let square_abs_m1 = T::mul_add(y, y, product) + T::mul_add(x, sum_error, product_error);
+        Self::new(
+            T::ln_1p(square_abs_m1) / T::two(),
+            T::atan2(y, T::one() + x),
+        )
+    }
+
     /// Returns the logarithm base 10 of this [`Complex`].
     pub fn log(self) -> Self {
         Self::ln(self) / T::ln(T::two() * (T::two() * T::two() + T::one()))
@@ -198,25 +462,56 @@ impl<T: Float> Complex<T> {
 
 // Trig
 impl<T: Float> Complex<T> {
+    /// Returns i times this [`Complex`] without rounding or NaNs from multiplying by zero.
+    fn mul_i(self) -> Self {
+        Self::new(-self.imag, self.real)
+    }
+
+    /// Returns -i times this [`Complex`] without rounding or NaNs from multiplying by zero.
+    fn mul_neg_i(self) -> Self {
+        Self::new(self.imag, -self.real)
+    }
+
     /// Returns the sine of this [`Complex`].
     pub fn sin(self) -> Self {
-        Self::new(
-            T::sin(self.real) * T::cosh(self.imag),
-            T::cos(self.real) * T::sinh(self.imag),
-        )
+        Self::mul_neg_i(Self::sinh(Self::mul_i(self)))
     }
 
     /// Returns the cosine of this [`Complex`].
     pub fn cos(self) -> Self {
+        Self::cosh(Self::mul_i(self))
+    }
+
+    /// Returns the cosine of this [`Complex`], minus one.
+    ///
+    /// This is accurate for small numbers, where `cos(z) - 1` cancels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::new(1e-10f64, 0.0);
+    ///
+    /// assert_eq!((z.cos() - 1.0).real(), 0.0);
+    /// assert!((z.cos_m1().real() + 5e-21).abs() < 1e-36);
+    /// ```
+    pub fn cos_m1(self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if !x.is_finite() || !y.is_finite() || T::abs(y) > T::ln(T::max_value()) {
+            return Self::cos(self) - T::one();
+        }
+
+        // cos(x) cosh(y) - 1 = (cos(x) - 1) cosh(y) + (cosh(y) - 1)
         Self::new(
-            T::cos(self.real) * T::cosh(self.imag),
-            -T::sin(self.real) * T::sinh(self.imag),
+            T::mul_add(x.cos_m1(), T::cosh(y), y.cosh_m1()),
+            -T::sin(x) * T::sinh(y),
         )
     }
 
     /// Returns the tangent of this [`Complex`].
     pub fn tan(self) -> Self {
-        Self::sin(self) / Self::cos(self)
+        Self::mul_neg_i(Self::tanh(Self::mul_i(self)))
     }
 
     /// Returns the cotangent of this [`Complex`].
@@ -236,19 +531,155 @@ impl<T: Float> Complex<T> {
 
     // Inverse trig
 
+    /// Returns the real parts of arcsin(x + iy) and arccos(x + iy) and the magnitude of their
+    /// imaginary parts, for finite x >= 0 and y >= 0.
+    ///
+    /// This is the algorithm of Hull, Fairgrieve and Tang, "Implementing the complex arcsine and
+    /// arccosine functions using exception handling" (1997), which is accurate to a few ulps.
+    fn arcsin_arccos(x: T, y: T) -> (T, T, T) {
+        let half = T::one() / T::two();
+        let half_pi = T::pi() / T::two();
+        let ln_two = T::ln(T::two());
+        let safe_max = T::sqrt(T::max_value()) / (T::two() * T::two() * T::two());
+        let safe_min = T::sqrt(T::min_positive_value()) * T::two() * T::two();
+        let a_crossover = T::from(1.5).unwrap();
+        let b_crossover = T::from(0.6417).unwrap();
+
+        let x_plus_1 = x + T::one();
+        let x_minus_1 = x - T::one();
+
+        if x > safe_min && x < safe_max && y > safe_min && y < safe_max {
+            let yy = y * y;
+            // r = |z + 1|, s = |z - 1| and a = (r + s) / 2 >= 1
+            let r = T::sqrt(x_plus_1 * x_plus_1 + yy);
+            let s = T::sqrt(x_minus_1 * x_minus_1 + yy);
+            let a = half * (r + s);
+            let b = x / a;
+
+            // arcsin(b) and arccos(b) lose accuracy as b approaches 1, so they are rewritten as atan
+            let (arcsin, arccos) = if b <= b_crossover {
+                (T::asin(b), T::acos(b))
+            } else {
+                let a_plus_x = a + x;
+                let w = if x <= T::one() {
+                    T::sqrt(half * a_plus_x * (yy / (r + x_plus_1) + (s - x_minus_1)))
+                } else {
+                    y * T::sqrt(half * (a_plus_x / (r + x_plus_1) + a_plus_x / (s + x_minus_1)))
+                };
+                (T::atan(x / w), T::atan(w / x))
+            };
+
+            // ln(a + sqrt(a^2 - 1)) cancels for a close to 1, so a - 1 is computed directly
+            let imag = if a <= a_crossover {
+                let a_minus_1 = if x < T::one() {
+                    half * (yy / (r + x_plus_1) + yy / (s - x_minus_1))
+                } else {
+                    half * (yy / (r + x_plus_1) + (s + x_minus_1))
+                };
+                T::ln_1p(a_minus_1 + T::sqrt(a_minus_1 * (a + T::one())))
+            } else {
+                T::ln(a + T::sqrt(a * a - T::one()))
+            };
+            return (arcsin, arccos, imag);
+        }
+
+        // Close to the real axis
+        if y <= T::epsilon() * T::abs(x_minus_1) {
+            return if x < T::one() {
+                (
+                    T::asin(x),
+                    T::acos(x),
+                    y / T::sqrt(x_plus_1 * (T::one() - x)),
+                )
+            } else if T::max_value() / x_plus_1 > x_minus_1 {
+                let root = T::sqrt(x_plus_1 * x_minus_1);
+                // y is zero whenever the root is, at the branch point z = 1
+                let arccos = if root == T::zero() { y } else { y / root };
+                (half_pi, arccos, T::ln_1p(x_minus_1 + root))
+            } else {
+                (half_pi, y / x, ln_two + T::ln(x))
+            };
+        }
+        // Only reachable for x = 1 and a tiny y
+        if y <= safe_min {
+            let root = T::sqrt(y);
+            return (half_pi - root, root, root);
+        }
+        // Very large y compared to x
+        if T::epsilon() * y - T::one() >= x {
+            return (x / y, half_pi, ln_two + T::ln(y));
+        }
+        if x > T::one() {
+            let x_over_y = x / y;
+            let imag = ln_two + T::ln(y) + half * T::ln_1p(x_over_y * x_over_y);
+            return (T::atan(x_over_y), T::atan(y / x), imag);
+        }
+        // Tiny x
+        let a = T::sqrt(T::one() + y * y);
+        (x / a, half_pi, half * T::ln_1p(T::two() * y * (y + a)))
+    }
+
     /// Returns the arcsine of this [`Complex`].
+    ///
+    /// The branch cuts lie along the real axis below -1 and above 1, the sign of a zero imaginary
+    /// part picks the side of the cut. The real part is on [-PI / 2, PI / 2].
     pub fn arcsin(self) -> Self {
-        -Self::i() * Self::ln(Self::sqrt(-self.powi(2) + T::one()) + Self::i() * self)
+        Self::mul_neg_i(Self::arcsinh(Self::mul_i(self)))
     }
 
     /// Returns the arccosine of this [`Complex`].
+    ///
+    /// The branch cuts lie along the real axis below -1 and above 1, the sign of a zero imaginary
+    /// part picks the side of the cut. The real part is on [0, PI].
     pub fn arccos(self) -> Self {
-        Self::i() * Self::ln(Self::sqrt(-self.powi(2) + T::one()) / Self::i() + self)
+        let (x, y) = (self.real, self.imag);
+        if x.is_infinite() {
+            let real = if y.is_nan() {
+                y
+            } else if y.is_infinite() {
+                if x > T::zero() {
+                    T::pi() / (T::two() * T::two())
+                } else {
+                    T::pi() * (T::one() + T::two()) / (T::two() * T::two())
+                }
+            } else if x > T::zero() {
+                T::zero()
+            } else {
+                T::pi()
+            };
+            return Self::new(real, -T::copysign(T::infinity(), y));
+        }
+        if y.is_infinite() {
+            let real = if x.is_nan() { x } else { T::pi() / T::two() };
+            return Self::new(real, -y);
+        }
+        if x.is_nan() || y.is_nan() {
+            let real = if x == T::zero() {
+                T::pi() / T::two()
+            } else {
+                T::nan()
+            };
+            return Self::new(real, T::nan());
+        }
+        if x == T::zero() && y == T::zero() {
+            return Self::new(T::pi() / T::two(), -y);
+        }
+
+        let (_, real, imag) = Self::arcsin_arccos(T::abs(x), T::abs(y));
+        let real = if x.is_sign_negative() {
+            T::pi() - real
+        } else {
+            real
+        };
+        Self::new(real, -T::copysign(imag, y))
     }
 
     /// Returns the arctangent of this [`Complex`].
+    ///
+    /// The branch cuts lie along the imaginary axis below -i and above i, the sign of a zero real
+    /// part picks the side of the cut. The real part is on [-PI / 2, PI / 2].
     pub fn arctan(self) -> Self {
-        Self::arcsin(self / Self::sqrt(self.powi(2) + T::one()))
+        Self::mul_neg_i(Self::arctanh(Self::mul_i(self)))
     }
 
     /// Returns the arccotangent of this [`Complex`].
@@ -270,23 +701,103 @@ impl<T: Float> Complex<T> {
 
     /// Returns the hyperbolic sine of this [`Complex`].
     pub fn sinh(self) -> Self {
-        Self::new(
-            T::sinh(self.real) * T::cos(self.imag),
-            T::cosh(self.real) * T::sin(self.imag),
-        )
+        let (x, y) = (self.real, self.imag);
+        if y == T::zero() {
+            // Keeps the sign of the zero and avoids inf * 0 for infinite x
+            return Self::new(T::sinh(x), y);
+        }
+        if !y.is_finite() {
+            return if x == T::zero() || x.is_infinite() {
+                Self::new(x, T::nan())
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+
+        // sinh(x) and cosh(x) can overflow while the result doesn't, so e^|x| is applied in two halves
+        if T::abs(x) > T::ln(T::max_value()) {
+            let half = T::exp(T::abs(x) / T::two());
+            let quarter = half / T::two();
+            return Self::new(
+                T::copysign(quarter, x) * T::cos(y) * half,
+                quarter * T::sin(y) * half,
+            );
+        }
+        Self::new(T::sinh(x) * T::cos(y), T::cosh(x) * T::sin(y))
     }
 
     /// Returns the hyperbolic cosine of this [`Complex`].
     pub fn cosh(self) -> Self {
-        Self::new(
-            T::cosh(self.real) * T::cos(self.imag),
-            T::sinh(self.real) * T::sin(self.imag),
-        )
+        let (x, y) = (self.real, self.imag);
+        if y == T::zero() {
+            return Self::new(T::cosh(x), T::copysign(T::zero(), x) * y);
+        }
+        if !y.is_finite() {
+            return if x == T::zero() {
+                Self::new(T::nan(), x)
+            } else if x.is_infinite() {
+                Self::new(T::abs(x), T::nan())
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+
+        // Like sinh, e^|x| is applied in two halves so the intermediate result doesn't overflow
+        if T::abs(x) > T::ln(T::max_value()) {
+            let half = T::exp(T::abs(x) / T::two());
+            let quarter = half / T::two();
+            return Self::new(
+                quarter * T::cos(y) * half,
+                T::copysign(quarter, x) * T::sin(y) * half,
+            );
+        }
+        Self::new(T::cosh(x) * T::cos(y), T::sinh(x) * T::sin(y))
     }
 
     /// Returns the hyperbolic tangent of this [`Complex`].
     pub fn tanh(self) -> Self {
-        Self::sinh(self) / Self::cosh(self)
+        let (x, y) = (self.real, self.imag);
+        if x.is_infinite() {
+            // tanh(±inf + iy) = ±1 + 0 sin(2y) i
+            let imag = if y.is_finite() {
+                T::copysign(T::zero(), T::sin(y) * T::cos(y))
+            } else {
+                T::copysign(T::zero(), y)
+            };
+            return Self::new(T::copysign(T::one(), x), imag);
+        }
+        if x.is_nan() {
+            return if y == T::zero() {
+                self
+            } else {
+                Self::new(x, x)
+            };
+        }
+        if !y.is_finite() {
+            return if x == T::zero() {
+                Self::new(x, T::nan())
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+
+        // From here on tanh(x) rounds to ±1, and the imaginary part decays like e^(-2|x|)
+        if T::abs(x) > -T::ln(T::epsilon()) {
+            let four = T::two() * T::two();
+            let decay = T::exp(-T::two() * T::abs(x));
+            return Self::new(
+                T::copysign(T::one(), x),
+                four * T::sin(y) * T::cos(y) * decay,
+            );
+        }
+
+        // Kahan's formula, which doesn't overflow in sinh / cosh for moderately large x
+        let t = T::tan(y);
+        let beta = T::one() + t * t;
+        let s = T::sinh(x);
+        let rho = T::sqrt(T::one() + s * s);
+        let denominator = T::one() + beta * s * s;
+        Self::new(beta * rho * s / denominator, t / denominator)
     }
 
     /// Returns the hyperbolic cotangent of this [`Complex`].
@@ -307,18 +818,150 @@ impl<T: Float> Complex<T> {
     // Inverse hyperbolic trig
 
     /// Returns the hyperbolic arcsine of this [`Complex`].
+    ///
+    /// The branch cuts lie along the imaginary axis below -i and above i, the sign of a zero real
+    /// part picks the side of the cut. The imaginary part is on [-PI / 2, PI / 2].
     pub fn arcsinh(self) -> Self {
-        Self::ln(Self::sqrt(self.powi(2) + T::one()) + self)
+        let (x, y) = (self.real, self.imag);
+        if x.is_infinite() {
+            let imag = if y.is_nan() {
+                y
+            } else if y.is_infinite() {
+                T::copysign(T::pi() / (T::two() * T::two()), y)
+            } else {
+                T::copysign(T::zero(), y)
+            };
+            return Self::new(x, imag);
+        }
+        if y.is_infinite() {
+            return if x.is_nan() {
+                Self::new(T::infinity(), x)
+            } else {
+                Self::new(
+                    T::copysign(T::infinity(), x),
+                    T::copysign(T::pi() / T::two(), y),
+                )
+            };
+        }
+        if x.is_nan() || y.is_nan() {
+            return if x.is_nan() && y == T::zero() {
+                self
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        if x == T::zero() && y == T::zero() {
+            return self;
+        }
+
+        // arcsinh(z) = -i arcsin(iz), which swaps the parts
+        let (real, _, imag) = Self::arcsin_arccos(T::abs(y), T::abs(x));
+        Self::new(T::copysign(imag, x), T::copysign(real, y))
     }
 
     /// Returns the hyperbolic arccosine of this [`Complex`].
+    ///
+    /// The branch cut lies along the real axis below 1, the sign of a zero imaginary part picks the
+    /// side of the cut. The real part is non-negative and the imaginary part is on [-PI, PI].
     pub fn arccosh(self) -> Self {
-        Self::ln(Self::sqrt(self.powi(2) - T::one()) + self)
+        let (x, y) = (self.real, self.imag);
+        if x.is_infinite() {
+            let imag = if y.is_nan() {
+                y
+            } else if y.is_infinite() {
+                if x > T::zero() {
+                    T::pi() / (T::two() * T::two())
+                } else {
+                    T::pi() * (T::one() + T::two()) / (T::two() * T::two())
+                }
+            } else if x > T::zero() {
+                T::zero()
+            } else {
+                T::pi()
+            };
+            return Self::new(T::infinity(), T::copysign(imag, y));
+        }
+        if y.is_infinite() {
+            let imag = if x.is_nan() {
+                x
+            } else {
+                T::copysign(T::pi() / T::two(), y)
+            };
+            return Self::new(T::infinity(), imag);
+        }
+        if x.is_nan() || y.is_nan() {
+            return Self::new(T::nan(), T::nan());
+        }
+        if x == T::zero() && y == T::zero() {
+            return Self::new(T::zero(), T::copysign(T::pi() / T::two(), y));
+        }
+
+        // arccosh(z) = ±i arccos(z), with the sign that puts the real part on [0, inf)
+        let (_, real, imag) = Self::arcsin_arccos(T::abs(x), T::abs(y));
+        let real = if x.is_sign_negative() {
+            T::pi() - real
+        } else {
+            real
+        };
+        Self::new(imag, T::copysign(real, y))
     }
 
     /// Returns the hyperbolic arctangent of this [`Complex`].
+    ///
+    /// The branch cuts lie along the real axis below -1 and above 1, the sign of a zero imaginary
+    /// part picks the side of the cut. The imaginary part is on [-PI / 2, PI / 2].
     pub fn arctanh(self) -> Self {
-        Self::ln((self + T::one()) / (-self + T::one())) * T::powi(T::two(), -1)
+        let (x, y) = (self.real, self.imag);
+        if x.is_infinite() || y.is_infinite() {
+            let imag = if y.is_nan() {
+                y
+            } else {
+                T::copysign(T::pi() / T::two(), y)
+            };
+            return Self::new(T::copysign(T::zero(), x), imag);
+        }
+        if x.is_nan() || y.is_nan() {
+            return if x == T::zero() {
+                Self::new(x, T::nan())
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        if y == T::zero() && T::abs(x) == T::one() {
+            return Self::new(T::copysign(T::infinity(), x), y);
+        }
+        if x == T::zero() && y == T::zero() {
+            return self;
+        }
+
+        // Kahan's algorithm, working on |x| and |y| and restoring the signs at the end
+        let (ax, ay) = (T::abs(x), T::abs(y));
+        let root_epsilon = T::sqrt(T::epsilon());
+
+        // arctanh(z) = z + z^3 / 3 + ..., so z itself is correctly rounded
+        if ax < root_epsilon && ay < root_epsilon {
+            return self;
+        }
+        // arctanh(z) = 1 / z ± PI / 2 i + O(1 / z^3)
+        if ax > T::one() / T::epsilon() || ay > T::one() / T::epsilon() {
+            return Self::new(Self::inv(self).real, T::copysign(T::pi() / T::two(), y));
+        }
+
+        let four = T::two() * T::two();
+        let real = if ax == T::one() && ay < root_epsilon {
+            (T::ln(T::two()) - T::ln(ay)) / T::two()
+        } else {
+            let distance = (ax - T::one()) * (ax - T::one()) + ay * ay;
+            T::ln_1p(four * ax / distance) / four
+        };
+        let imag = if ax == T::one() {
+            T::atan2(T::two(), -ay) / T::two()
+        } else if ay < T::epsilon() {
+            T::atan2(T::two() * ay, (T::one() - ax) * (T::one() + ax)) / T::two()
+        } else {
+            T::atan2(T::two() * ay, (T::one() - ax) * (T::one() + ax) - ay * ay) / T::two()
+        };
+        Self::new(T::copysign(real, x), T::copysign(imag, y))
     }
 
     /// Returns the hyperbolic arccotangent of this [`Complex`].
@@ -337,18 +980,36 @@ impl<T: Float> Complex<T> {
     }
 }
 
-// Implements display
-impl<T: Float + Display> Display for Complex<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        if self.imag >= T::zero() {
-            write!(f, "{} + {}i", self.real, self.imag)
-        } else {
-            write!(f, "{} - {}i", self.real, self.imag)
-        }
-    }
-}
-
+mod branches;
+mod checked;
+mod comparison;
+mod components;
+mod constants;
+mod conversions;
+pub mod fixed;
+mod formatting;
+pub mod gaussian;
+mod numeric;
+mod ordering;
 mod overloading;
+mod parsing;
+mod polar;
+pub mod quadratic;
+#[cfg(feature = "rational")]
+pub mod rational;
+#[cfg(feature = "serde")]
+pub mod serialization;
+mod summation;
+
+pub use branches::Roots;
+pub use checked::ComplexError;
+#[doc(hidden)]
+pub use comparison::{assert_complex_eq_impl, assert_complex_relative_eq_impl};
+pub use formatting::{DisplayMarkup, DisplayPolar};
+pub use ordering::TotalComplex;
+pub use overloading::ComplexPart;
+pub use parsing::{ParseComplexError, ParseComplexErrorKind};
+pub use polar::Polar;
 
 #[cfg(test)]
 mod tests;
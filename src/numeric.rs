@@ -0,0 +1,242 @@
+// Implementations of the num-traits numeric traits, so Complex can be used in generic numeric code
+use crate::parsing::{ParseComplexError, parse_complex};
+use crate::{Complex, ComplexPart};
+use core::ops::Neg;
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, ConstOne, ConstZero, Float, FromPrimitive, Inv,
+    MulAdd, MulAddAssign, Num, NumCast, One, Pow, ToPrimitive, WrappingAdd, WrappingMul,
+    WrappingNeg, WrappingSub, Zero,
+};
+
+// Identities
+
+impl<T: Num + Clone> Zero for Complex<T> {
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.imag.is_zero()
+    }
+}
+
+impl<T: ComplexPart> One for Complex<T> {
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.real.is_one() && self.imag.is_zero()
+    }
+}
+
+impl<T: Num + Clone + ConstZero> ConstZero for Complex<T> {
+    const ZERO: Self = Complex {
+        real: T::ZERO,
+        imag: T::ZERO,
+    };
+}
+
+impl<T: ComplexPart + ConstZero + ConstOne> ConstOne for Complex<T> {
+    const ONE: Self = Complex {
+        real: T::ONE,
+        imag: T::ZERO,
+    };
+}
+
+impl<T: ComplexPart + Neg<Output = T>> Num for Complex<T> {
+    type FromStrRadixErr = ParseComplexError;
+
+    /// Parses a [`Complex`] in the given radix, which must be between 2 and 18 so i isn't a digit.
+    ///
+    /// Exponents like `1e-5` are only accepted in radix 10.
+    fn from_str_radix(input: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        parse_complex(input, radix)
+    }
+}
+
+// Operators
+
+impl<T: Float> Inv for Complex<T> {
+    type Output = Complex<T>;
+
+    fn inv(self) -> Complex<T> {
+        Complex::inv(self)
+    }
+}
+
+impl<T: Float> Pow<i64> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn pow(self, exponent: i64) -> Complex<T> {
+        Complex::powi(self, exponent)
+    }
+}
+
+// Pow<T> can't be generic, it would overlap with Pow<i64> if i64 ever implemented Float
+impl Pow<f32> for Complex<f32> {
+    type Output = Complex<f32>;
+
+    fn pow(self, exponent: f32) -> Complex<f32> {
+        Complex::powf(self, exponent)
+    }
+}
+
+impl Pow<f64> for Complex<f64> {
+    type Output = Complex<f64>;
+
+    fn pow(self, exponent: f64) -> Complex<f64> {
+        Complex::powf(self, exponent)
+    }
+}
+
+impl<T: Float> Pow<Complex<T>> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn pow(self, exponent: Complex<T>) -> Complex<T> {
+        Complex::powc(self, exponent)
+    }
+}
+
+/// Computes self * a + b with fused multiply-adds on every component
+impl<T: Float> MulAdd for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul_add(self, a: Complex<T>, b: Complex<T>) -> Complex<T> {
+        Complex::new(
+            T::mul_add(self.real, a.real, T::mul_add(-self.imag, a.imag, b.real)),
+            T::mul_add(self.real, a.imag, T::mul_add(self.imag, a.real, b.imag)),
+        )
+    }
+}
+
+impl<T: Float> MulAddAssign for Complex<T> {
+    fn mul_add_assign(&mut self, a: Complex<T>, b: Complex<T>) {
+        *self = MulAdd::mul_add(*self, a, b);
+    }
+}
+
+// Checked and wrapping arithmetic, for integer parts
+//
+// These are traits rather than inherent methods, the float checked_* methods already use the names
+
+impl<T: Num + Clone + CheckedAdd> CheckedAdd for Complex<T> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(Complex::new(
+            self.real.checked_add(&rhs.real)?,
+            self.imag.checked_add(&rhs.imag)?,
+        ))
+    }
+}
+
+impl<T: Num + Clone + CheckedSub> CheckedSub for Complex<T> {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(Complex::new(
+            self.real.checked_sub(&rhs.real)?,
+            self.imag.checked_sub(&rhs.imag)?,
+        ))
+    }
+}
+
+/// Fails if any of the four products or the two sums overflows, even if the result would fit
+impl<T: ComplexPart + CheckedAdd + CheckedSub + CheckedMul> CheckedMul for Complex<T> {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let (a, b, c, d) = (&self.real, &self.imag, &rhs.real, &rhs.imag);
+        Some(Complex::new(
+            a.checked_mul(c)?.checked_sub(&b.checked_mul(d)?)?,
+            a.checked_mul(d)?.checked_add(&b.checked_mul(c)?)?,
+        ))
+    }
+}
+
+impl<T: CheckedNeg> CheckedNeg for Complex<T> {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(Complex::new(
+            self.real.checked_neg()?,
+            self.imag.checked_neg()?,
+        ))
+    }
+}
+
+impl<T: Num + Clone + WrappingAdd> WrappingAdd for Complex<T> {
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        Complex::new(
+            self.real.wrapping_add(&rhs.real),
+            self.imag.wrapping_add(&rhs.imag),
+        )
+    }
+}
+
+impl<T: Num + Clone + WrappingSub> WrappingSub for Complex<T> {
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        Complex::new(
+            self.real.wrapping_sub(&rhs.real),
+            self.imag.wrapping_sub(&rhs.imag),
+        )
+    }
+}
+
+impl<T: ComplexPart + WrappingAdd + WrappingSub + WrappingMul> WrappingMul for Complex<T> {
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        let (a, b, c, d) = (&self.real, &self.imag, &rhs.real, &rhs.imag);
+        Complex::new(
+            a.wrapping_mul(c).wrapping_sub(&b.wrapping_mul(d)),
+            a.wrapping_mul(d).wrapping_add(&b.wrapping_mul(c)),
+        )
+    }
+}
+
+impl<T: WrappingNeg> WrappingNeg for Complex<T> {
+    fn wrapping_neg(&self) -> Self {
+        Complex::new(self.real.wrapping_neg(), self.imag.wrapping_neg())
+    }
+}
+
+// Conversions
+
+/// Only succeeds for purely real numbers
+impl<T: Float> ToPrimitive for Complex<T> {
+    fn to_i64(&self) -> Option<i64> {
+        if self.imag.is_zero() {
+            self.real.to_i64()
+        } else {
+            None
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        if self.imag.is_zero() {
+            self.real.to_u64()
+        } else {
+            None
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        if self.imag.is_zero() {
+            self.real.to_f64()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Float> FromPrimitive for Complex<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from(n).map(|real| Self::new(real, T::zero()))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from(n).map(|real| Self::new(real, T::zero()))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from(n).map(|real| Self::new(real, T::zero()))
+    }
+}
+
+impl<T: Float> NumCast for Complex<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        T::from(n).map(|real| Self::new(real, T::zero()))
+    }
+}
@@ -0,0 +1,167 @@
+// Total ordering and hashing, so complex numbers can be sorted and used as keys
+use crate::Complex;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use num_traits::Float;
+
+/// Maps -0.0 onto 0.0 and every NaN onto the same NaN.
+fn canonical<T: Float>(value: T) -> T {
+    if value.is_nan() {
+        T::nan()
+    } else if value == T::zero() {
+        T::zero()
+    } else {
+        value
+    }
+}
+
+/// Orders floats like IEEE 754 totalOrder after canonicalising, so the zeros are equal and NaN
+/// comes after positive infinity.
+fn total_cmp<T: Float>(a: T, b: T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// Compares the real parts and then the imaginary parts with a total order.
+    ///
+    /// -0.0 and 0.0 are equal, and NaN is equal to itself and larger than positive infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let mut numbers = [
+    ///     Complex::new(f64::NAN, 0.0),
+    ///     Complex::new(1.0, 2.0),
+    ///     Complex::new(1.0, -2.0),
+    ///     Complex::new(-3.0, 5.0),
+    /// ];
+    /// numbers.sort_by(Complex::total_cmp);
+    ///
+    /// assert_eq!(numbers[..3], [Complex::new(-3.0, 5.0), Complex::new(1.0, -2.0), Complex::new(1.0, 2.0)]);
+    /// assert!(numbers[3].real().is_nan());
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        total_cmp(self.real, other.real).then_with(|| total_cmp(self.imag, other.imag))
+    }
+
+    /// Compares by absolute value, ties are broken by argument and then by [`Complex::total_cmp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let mut numbers = [Complex::new(3.0, 4.0), Complex::new(0.0, -1.0), Complex::new(-2.0, 0.0)];
+    /// numbers.sort_by(Complex::cmp_by_modulus);
+    ///
+    /// assert_eq!(numbers, [Complex::new(0.0, -1.0), Complex::new(-2.0, 0.0), Complex::new(3.0, 4.0)]);
+    /// ```
+    pub fn cmp_by_modulus(&self, other: &Self) -> Ordering {
+        total_cmp(self.abs(), other.abs())
+            .then_with(|| total_cmp(self.arg(), other.arg()))
+            .then_with(|| self.total_cmp(other))
+    }
+
+    /// Compares by argument on [-PI, PI], ties are broken by absolute value and then by
+    /// [`Complex::total_cmp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let mut numbers = [Complex::new(-1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)];
+    /// numbers.sort_by(Complex::cmp_by_arg);
+    ///
+    /// assert_eq!(numbers, [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0), Complex::new(-1.0, 0.0)]);
+    /// ```
+    pub fn cmp_by_arg(&self, other: &Self) -> Ordering {
+        total_cmp(self.arg(), other.arg())
+            .then_with(|| total_cmp(self.abs(), other.abs()))
+            .then_with(|| self.total_cmp(other))
+    }
+}
+
+/// Wrapper around [`Complex`] that implements [`Eq`], [`Ord`] and [`Hash`], so it can be used as
+/// a key in a `HashMap` or `BTreeMap`.
+///
+/// Numbers are ordered lexicographically with [`Complex::total_cmp`]. -0.0 and 0.0 are equal, all
+/// NaNs are equal to each other, and equal numbers hash the same.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::{Complex, TotalComplex};
+/// use std::collections::HashSet;
+///
+/// let set: HashSet<_> = [Complex::new(0.0, 1.0), Complex::new(-0.0, 1.0), Complex::new(f64::NAN, 0.0)]
+///     .into_iter()
+///     .map(TotalComplex::new)
+///     .collect();
+///
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(&TotalComplex::new(Complex::new(0.0, 1.0))));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TotalComplex<T: Float>(pub Complex<T>);
+
+impl<T: Float> TotalComplex<T> {
+    /// Creates a new [`TotalComplex`].
+    pub fn new(z: Complex<T>) -> Self {
+        Self(z)
+    }
+
+    /// Returns the wrapped [`Complex`].
+    pub fn into_inner(self) -> Complex<T> {
+        self.0
+    }
+}
+
+impl<T: Float> PartialEq for TotalComplex<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for TotalComplex<T> {}
+
+impl<T: Float> PartialOrd for TotalComplex<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for TotalComplex<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Hashes the bits of the canonical parts, so -0.0 hashes like 0.0 and every NaN alike
+impl<T: Float> Hash for TotalComplex<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        canonical(self.0.real).integer_decode().hash(state);
+        canonical(self.0.imag).integer_decode().hash(state);
+    }
+}
+
+/// Complex<T> -> TotalComplex<T>
+impl<T: Float> From<Complex<T>> for TotalComplex<T> {
+    fn from(z: Complex<T>) -> TotalComplex<T> {
+        TotalComplex(z)
+    }
+}
+
+/// TotalComplex<T> -> Complex<T>
+impl<T: Float> From<TotalComplex<T>> for Complex<T> {
+    fn from(z: TotalComplex<T>) -> Complex<T> {
+        z.0
+    }
+}
@@ -1,11 +1,14 @@
-use crate::Complex;
-use num_traits::{self, Float};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::polar::normalize_angle;
+use crate::{Complex, Polar};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use num_traits::{self, Float, Num};
 
 // Addition
 
 /// Complex<T> + T
-impl<T: Float> Add<T> for Complex<T> {
+impl<T: Num + Clone> Add<T> for Complex<T> {
     type Output = Complex<T>;
 
     fn add(self, rhs: T) -> Complex<T> {
@@ -14,9 +17,9 @@ impl<T: Float> Add<T> for Complex<T> {
 }
 
 /// Complex<T> += T
-impl<T: Float> AddAssign<T> for Complex<T> {
+impl<T: Num + Clone> AddAssign<T> for Complex<T> {
     fn add_assign(&mut self, rhs: T) {
-        *self = Complex::new(self.real + rhs, self.imag);
+        *self = self.clone() + rhs;
     }
 }
 
@@ -39,7 +42,7 @@ impl Add<Complex<f64>> for f64 {
 }
 
 /// Complex<T> + Complex<T>
-impl<T: Float> Add<Complex<T>> for Complex<T> {
+impl<T: Num + Clone> Add<Complex<T>> for Complex<T> {
     type Output = Complex<T>;
 
     fn add(self, rhs: Complex<T>) -> Complex<T> {
@@ -48,16 +51,16 @@ impl<T: Float> Add<Complex<T>> for Complex<T> {
 }
 
 /// Complex<T> += Complex<T>
-impl<T: Float> AddAssign<Complex<T>> for Complex<T> {
+impl<T: Num + Clone> AddAssign<Complex<T>> for Complex<T> {
     fn add_assign(&mut self, rhs: Complex<T>) {
-        *self = Complex::new(self.real + rhs.real, self.imag + rhs.imag);
+        *self = self.clone() + rhs;
     }
 }
 
 // Subtraction
 
 /// Complex<T> - T
-impl<T: Float> Sub<T> for Complex<T> {
+impl<T: Num + Clone> Sub<T> for Complex<T> {
     type Output = Complex<T>;
 
     fn sub(self, rhs: T) -> Complex<T> {
@@ -66,9 +69,9 @@ impl<T: Float> Sub<T> for Complex<T> {
 }
 
 /// Complex<T> -= T
-impl<T: Float> SubAssign<T> for Complex<T> {
+impl<T: Num + Clone> SubAssign<T> for Complex<T> {
     fn sub_assign(&mut self, rhs: T) {
-        *self = Complex::new(self.real - rhs, self.imag);
+        *self = self.clone() - rhs;
     }
 }
 
@@ -91,25 +94,25 @@ impl Sub<Complex<f64>> for f64 {
 }
 
 /// Complex<T> - Complex<T>
-impl<T: Float> Sub<Complex<T>> for Complex<T> {
+impl<T: Num + Clone> Sub<Complex<T>> for Complex<T> {
     type Output = Complex<T>;
 
     fn sub(self, rhs: Complex<T>) -> Complex<T> {
-        Complex::new(self.real - rhs.real, self.imag + rhs.imag)
+        Complex::new(self.real - rhs.real, self.imag - rhs.imag)
     }
 }
 
 /// Complex<T> -= Complex<T>
-impl<T: Float> SubAssign<Complex<T>> for Complex<T> {
+impl<T: Num + Clone> SubAssign<Complex<T>> for Complex<T> {
     fn sub_assign(&mut self, rhs: Complex<T>) {
-        *self = Complex::new(self.real - rhs.real, self.imag + rhs.imag);
+        *self = self.clone() - rhs;
     }
 }
 
 // Negation
 
 /// -Complex<T>
-impl<T: Float> Neg for Complex<T> {
+impl<T: Neg<Output = T>> Neg for Complex<T> {
     type Output = Complex<T>;
 
     fn neg(self) -> Complex<T> {
@@ -117,21 +120,102 @@ impl<T: Float> Neg for Complex<T> {
     }
 }
 
+// Parts
+
+/// Type that can be a part of a [`Complex`] that is multiplied, divided or taken the remainder of.
+///
+/// The default methods are exact: the schoolbook product, and the quotient as the product with the
+/// conjugate of the divisor over its norm, so integer quotients have both parts rounded towards
+/// zero. The floats override them to follow C99 Annex G for infinities, NaN and signed zeros, and
+/// to divide with an algorithm that doesn't overflow or underflow in between.
+///
+/// Implemented for the primitive floats and integers and `Wrapping` integers, for `BigInt` and
+/// `BigUint` with the `num-bigint` feature, and for `Ratio` with the `num-rational` feature, which
+/// `rational` enables. Multiplication and division need this trait rather than just `Num`, because
+/// the floats can't share a blanket implementation with the exact types. The orphan rules keep
+/// other crates from implementing it for their own types, so `*`, `/` and `%` are limited to
+/// these.
+pub trait ComplexPart: Num + Clone {
+    /// Returns lhs * rhs.
+    fn multiply(lhs: Complex<Self>, rhs: Complex<Self>) -> Complex<Self> {
+        let (a, b, c, d) = (lhs.real, lhs.imag, rhs.real, rhs.imag);
+        Complex::new(a.clone() * c.clone() - b.clone() * d.clone(), a * d + b * c)
+    }
+
+    /// Returns lhs / rhs.
+    fn divide(lhs: Complex<Self>, rhs: Complex<Self>) -> Complex<Self> {
+        let (a, b, c, d) = (lhs.real, lhs.imag, rhs.real, rhs.imag);
+        let norm = c.clone() * c.clone() + d.clone() * d.clone();
+        // (a + bi) (c - di) / (c² + d²)
+        let real = a.clone() * c.clone() + b.clone() * d.clone();
+        let imag = b * c - a * d;
+        Complex::new(real / norm.clone(), imag / norm)
+    }
+
+    /// Returns lhs % rhs, lhs minus rhs times the quotient with both parts rounded towards zero.
+    fn remainder(lhs: Complex<Self>, rhs: Complex<Self>) -> Complex<Self> {
+        // Integer quotients are already rounded towards zero, rational ones leave no remainder
+        let quotient = Self::divide(lhs.clone(), rhs.clone());
+        lhs - Self::multiply(rhs, quotient)
+    }
+}
+
+macro_rules! float_complex_part {
+    ($($Float:ty),*) => {$(
+        impl ComplexPart for $Float {
+            fn multiply(lhs: Complex<$Float>, rhs: Complex<$Float>) -> Complex<$Float> {
+                multiply(lhs, rhs)
+            }
+
+            fn divide(lhs: Complex<$Float>, rhs: Complex<$Float>) -> Complex<$Float> {
+                divide(lhs, rhs)
+            }
+
+            fn remainder(lhs: Complex<$Float>, rhs: Complex<$Float>) -> Complex<$Float> {
+                remainder(lhs, rhs)
+            }
+        }
+    )*};
+}
+
+float_complex_part!(f32, f64);
+
+macro_rules! exact_complex_part {
+    ($($Type:ty),*) => {$(
+        impl ComplexPart for $Type {}
+    )*};
+}
+
+exact_complex_part!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl<T> ComplexPart for core::num::Wrapping<T> where core::num::Wrapping<T>: Num + Clone {}
+
+#[cfg(feature = "num-rational")]
+impl<T> ComplexPart for num_rational::Ratio<T> where num_rational::Ratio<T>: Num + Clone {}
+
+#[cfg(feature = "num-bigint")]
+impl ComplexPart for num_bigint::BigInt {}
+
+#[cfg(feature = "num-bigint")]
+impl ComplexPart for num_bigint::BigUint {}
+
 // Multiplication
 
 /// Complex<T> * T
-impl<T: Float> Mul<T> for Complex<T> {
+impl<T: Num + Clone> Mul<T> for Complex<T> {
     type Output = Complex<T>;
 
     fn mul(self, rhs: T) -> Complex<T> {
-        Complex::new(self.real * rhs, self.imag * rhs)
+        Complex::new(self.real * rhs.clone(), self.imag * rhs)
     }
 }
 
 /// Complex<T> *= T
-impl<T: Float> MulAssign<T> for Complex<T> {
+impl<T: Num + Clone> MulAssign<T> for Complex<T> {
     fn mul_assign(&mut self, rhs: T) {
-        *self = Complex::new(self.real * rhs, self.imag * rhs);
+        *self = self.clone() * rhs;
     }
 }
 
@@ -154,42 +238,102 @@ impl Mul<Complex<f64>> for f64 {
 }
 
 /// Complex<T> * Complex<T>
-impl<T: Float> Mul<Complex<T>> for Complex<T> {
+impl<T: ComplexPart> Mul<Complex<T>> for Complex<T> {
     type Output = Complex<T>;
 
     fn mul(self, rhs: Complex<T>) -> Complex<T> {
-        Complex::new(
-            self.real * rhs.real - self.imag * rhs.imag,
-            self.real * rhs.imag + self.imag * rhs.real,
-        )
+        T::multiply(self, rhs)
     }
 }
 
 /// Complex<T> *= Complex<T>
-impl<T: Float> MulAssign<Complex<T>> for Complex<T> {
+impl<T: ComplexPart> MulAssign<Complex<T>> for Complex<T> {
     fn mul_assign(&mut self, rhs: Complex<T>) {
-        *self = Complex::new(
-            self.real * rhs.real - self.imag * rhs.imag,
-            self.real * rhs.imag + self.imag * rhs.real,
-        );
+        *self = T::multiply(self.clone(), rhs);
+    }
+}
+
+/// Returns 1 or 0 with the sign of value, depending on whether value is infinite.
+fn unit_if_infinite<T: Float>(value: T) -> T {
+    if value.is_infinite() {
+        T::copysign(T::one(), value)
+    } else {
+        T::copysign(T::zero(), value)
+    }
+}
+
+/// Returns a zero with the sign of value if value is NaN, and value otherwise.
+fn zero_if_nan<T: Float>(value: T) -> T {
+    if value.is_nan() {
+        T::copysign(T::zero(), value)
+    } else {
+        value
+    }
+}
+
+/// Multiplies two complex numbers, following C99 Annex G when infinities show up.
+///
+/// An infinity times a nonzero number is an infinity, even when the schoolbook formula produces
+/// inf - inf or 0 * inf and gives NaN for both parts.
+pub(crate) fn multiply<T: Float>(lhs: Complex<T>, rhs: Complex<T>) -> Complex<T> {
+    let (mut a, mut b, mut c, mut d) = (lhs.real, lhs.imag, rhs.real, rhs.imag);
+    let (ac, bd, ad, bc) = (a * c, b * d, a * d, b * c);
+    let (real, imag) = (ac - bd, ad + bc);
+    if !(real.is_nan() && imag.is_nan()) {
+        return Complex::new(real, imag);
+    }
+
+    let mut recalculate = false;
+    if a.is_infinite() || b.is_infinite() {
+        a = unit_if_infinite(a);
+        b = unit_if_infinite(b);
+        c = zero_if_nan(c);
+        d = zero_if_nan(d);
+        recalculate = true;
+    }
+    if c.is_infinite() || d.is_infinite() {
+        c = unit_if_infinite(c);
+        d = unit_if_infinite(d);
+        a = zero_if_nan(a);
+        b = zero_if_nan(b);
+        recalculate = true;
+    }
+    if !recalculate
+        && (ac.is_infinite() || bd.is_infinite() || ad.is_infinite() || bc.is_infinite())
+    {
+        // The products overflowed, so the NaNs came from inf - inf
+        a = zero_if_nan(a);
+        b = zero_if_nan(b);
+        c = zero_if_nan(c);
+        d = zero_if_nan(d);
+        recalculate = true;
+    }
+
+    if recalculate {
+        Complex::new(
+            T::infinity() * (a * c - b * d),
+            T::infinity() * (a * d + b * c),
+        )
+    } else {
+        Complex::new(real, imag)
     }
 }
 
 // Division
 
 /// Complex<T> / T
-impl<T: Float> Div<T> for Complex<T> {
+impl<T: Num + Clone> Div<T> for Complex<T> {
     type Output = Complex<T>;
 
     fn div(self, rhs: T) -> Complex<T> {
-        Complex::new(self.real / rhs, self.imag / rhs)
+        Complex::new(self.real / rhs.clone(), self.imag / rhs)
     }
 }
 
 /// Complex<T> /= T
-impl<T: Float> DivAssign<T> for Complex<T> {
+impl<T: Num + Clone> DivAssign<T> for Complex<T> {
     fn div_assign(&mut self, rhs: T) {
-        *self = Complex::new(self.real / rhs, self.imag / rhs);
+        *self = self.clone() / rhs;
     }
 }
 
@@ -198,7 +342,7 @@ impl Div<Complex<f32>> for f32 {
     type Output = Complex<f32>;
 
     fn div(self, rhs: Complex<f32>) -> Complex<f32> {
-        Complex::conj(rhs) / Complex::square_abs(rhs) * self
+        divide(Complex::new(self, 0f32), rhs)
     }
 }
 
@@ -207,30 +351,551 @@ impl Div<Complex<f64>> for f64 {
     type Output = Complex<f64>;
 
     fn div(self, rhs: Complex<f64>) -> Complex<f64> {
-        Complex::conj(rhs) / Complex::square_abs(rhs) * self
+        divide(Complex::new(self, 0f64), rhs)
     }
 }
 
 /// Complex<T> / Complex<T>
-impl<T: Float> Div<Complex<T>> for Complex<T> {
+impl<T: ComplexPart> Div<Complex<T>> for Complex<T> {
     type Output = Complex<T>;
 
     fn div(self, rhs: Complex<T>) -> Complex<T> {
-        let rhsinv = rhs.inv();
-        Complex::new(
-            self.real * rhsinv.real - self.imag * rhsinv.imag,
-            self.real * rhsinv.imag + self.imag * rhsinv.real,
-        )
+        T::divide(self, rhs)
     }
 }
 
 /// Complex<T> /= Complex<T>
-impl<T: Float> DivAssign<Complex<T>> for Complex<T> {
+impl<T: ComplexPart> DivAssign<Complex<T>> for Complex<T> {
     fn div_assign(&mut self, rhs: Complex<T>) {
-        let rhsinv = rhs.inv();
-        *self = Complex::new(
-            self.real * rhsinv.real - self.imag * rhsinv.imag,
-            self.real * rhsinv.imag + self.imag * rhsinv.real,
+        *self = T::divide(self.clone(), rhs);
+    }
+}
+
+/// Divides two complex numbers with the robust variant of Smith's algorithm by Baudin and Smith.
+///
+/// Unlike multiplying by the conjugate and dividing by |rhs|^2 this doesn't overflow or underflow
+/// in between, operands close to the edges of the exponent range are scaled towards the middle.
+pub(crate) fn divide<T: Float>(lhs: Complex<T>, rhs: Complex<T>) -> Complex<T> {
+    let (mut a, mut b, mut c, mut d) = (lhs.real, lhs.imag, rhs.real, rhs.imag);
+    let two = T::one() + T::one();
+    let half_max = T::max_value() / two;
+    let tiny = T::min_positive_value() * two / T::epsilon();
+    let blowup = two / (T::epsilon() * T::epsilon());
+    let mut scale = T::one();
+
+    let ab = T::max(T::abs(a), T::abs(b));
+    let cd = T::max(T::abs(c), T::abs(d));
+    if ab >= half_max {
+        a = a / two;
+        b = b / two;
+        scale = scale * two;
+    }
+    if cd >= half_max {
+        c = c / two;
+        d = d / two;
+        scale = scale / two;
+    }
+    if ab <= tiny {
+        a = a * blowup;
+        b = b * blowup;
+        scale = scale / blowup;
+    }
+    if cd <= tiny {
+        c = c * blowup;
+        d = d * blowup;
+        scale = scale * blowup;
+    }
+
+    let (real, imag) = if T::abs(d) <= T::abs(c) {
+        smith_quotient(a, b, c, d)
+    } else {
+        // Dividing by d + ci instead of c + di swaps the parts and flips the sign of the imaginary one
+        let (real, imag) = smith_quotient(b, a, d, c);
+        (real, -imag)
+    };
+    if real.is_nan() && imag.is_nan() {
+        return recover_quotient(lhs, rhs);
+    }
+    Complex::new(real * scale, imag * scale)
+}
+
+/// Returns lhs - rhs q, where q is the quotient with both parts rounded towards zero.
+pub(crate) fn remainder<T: Float>(lhs: Complex<T>, rhs: Complex<T>) -> Complex<T> {
+    let quotient = divide(lhs, rhs);
+    lhs - multiply(
+        rhs,
+        Complex::new(quotient.real.trunc(), quotient.imag.trunc()),
+    )
+}
+
+/// Recovers the infinities and zeros C99 Annex G expects from divisions that gave NaN for both parts.
+fn recover_quotient<T: Float>(lhs: Complex<T>, rhs: Complex<T>) -> Complex<T> {
+    let (a, b, c, d) = (lhs.real, lhs.imag, rhs.real, rhs.imag);
+    let lhs_finite = a.is_finite() && b.is_finite();
+    let rhs_finite = c.is_finite() && d.is_finite();
+
+    if c == T::zero() && d == T::zero() && (!a.is_nan() || !b.is_nan()) {
+        // Nonzero divided by zero
+        let infinity = T::copysign(T::infinity(), c);
+        Complex::new(infinity * a, infinity * b)
+    } else if (a.is_infinite() || b.is_infinite()) && rhs_finite {
+        // Infinite divided by finite
+        let (a, b) = (unit_if_infinite(a), unit_if_infinite(b));
+        Complex::new(
+            T::infinity() * (a * c + b * d),
+            T::infinity() * (b * c - a * d),
+        )
+    } else if (c.is_infinite() || d.is_infinite()) && lhs_finite {
+        // Finite divided by infinite
+        let (c, d) = (unit_if_infinite(c), unit_if_infinite(d));
+        Complex::new(T::zero() * (a * c + b * d), T::zero() * (b * c - a * d))
+    } else {
+        Complex::new(T::nan(), T::nan())
+    }
+}
+
+/// Returns (a + bi) / (c + di) for |d| <= |c|.
+fn smith_quotient<T: Float>(a: T, b: T, c: T, d: T) -> (T, T) {
+    let r = d / c;
+    let t = T::one() / (c + d * r);
+    (smith_part(a, b, c, d, r, t), smith_part(b, -a, c, d, r, t))
+}
+
+/// Returns (a + br) t, reordering the operations when br underflows to zero.
+fn smith_part<T: Float>(a: T, b: T, c: T, d: T, r: T, t: T) -> T {
+    if r != T::zero() {
+        let br = b * r;
+        if br != T::zero() {
+            (a + br) * t
+        } else {
+            a * t + (b * t) * r
+        }
+    } else if d == T::zero() {
+        // Skips d (b / c), which is zero but gives NaN when b / c overflows
+        a * t
+    } else {
+        (a + d * (b / c)) * t
+    }
+}
+
+// Remainder
+
+/// Complex<T> % Complex<T>
+impl<T: ComplexPart> Rem<Complex<T>> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn rem(self, rhs: Complex<T>) -> Complex<T> {
+        T::remainder(self, rhs)
+    }
+}
+
+/// Complex<T> %= Complex<T>
+impl<T: ComplexPart> RemAssign<Complex<T>> for Complex<T> {
+    fn rem_assign(&mut self, rhs: Complex<T>) {
+        *self = T::remainder(self.clone(), rhs);
+    }
+}
+
+/// Complex<T> % T, the remainder of both parts
+impl<T: Num + Clone> Rem<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn rem(self, rhs: T) -> Complex<T> {
+        Complex::new(self.real % rhs.clone(), self.imag % rhs)
+    }
+}
+
+/// Complex<T> %= T
+impl<T: Num + Clone> RemAssign<T> for Complex<T> {
+    fn rem_assign(&mut self, rhs: T) {
+        *self = self.clone() % rhs;
+    }
+}
+
+// References
+
+/// Implements an operator for every mix of owned and borrowed operands by cloning the borrowed
+/// ones and forwarding to the owned implementation.
+///
+/// The first bound applies to the operators between two complex numbers, the second to those
+/// with a scalar.
+macro_rules! forward_ref_binop {
+    ($Trait:ident, $method:ident, $TraitAssign:ident, $method_assign:ident, [$($Complex:tt)+], [$($Scalar:tt)+]) => {
+        /// &Complex<T> op &Complex<T>
+        impl<T: $($Complex)+> $Trait<&Complex<T>> for &Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: &Complex<T>) -> Complex<T> {
+                $Trait::$method(self.clone(), rhs.clone())
+            }
+        }
+
+        /// &Complex<T> op Complex<T>
+        impl<T: $($Complex)+> $Trait<Complex<T>> for &Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: Complex<T>) -> Complex<T> {
+                $Trait::$method(self.clone(), rhs)
+            }
+        }
+
+        /// Complex<T> op &Complex<T>
+        impl<T: $($Complex)+> $Trait<&Complex<T>> for Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: &Complex<T>) -> Complex<T> {
+                $Trait::$method(self, rhs.clone())
+            }
+        }
+
+        /// &Complex<T> op &T
+        impl<T: $($Scalar)+> $Trait<&T> for &Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: &T) -> Complex<T> {
+                $Trait::$method(self.clone(), rhs.clone())
+            }
+        }
+
+        /// &Complex<T> op T
+        impl<T: $($Scalar)+> $Trait<T> for &Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: T) -> Complex<T> {
+                $Trait::$method(self.clone(), rhs)
+            }
+        }
+
+        /// Complex<T> op &T
+        impl<T: $($Scalar)+> $Trait<&T> for Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: &T) -> Complex<T> {
+                $Trait::$method(self, rhs.clone())
+            }
+        }
+
+        /// Complex<T> op= &Complex<T>
+        impl<T: $($Complex)+> $TraitAssign<&Complex<T>> for Complex<T> {
+            fn $method_assign(&mut self, rhs: &Complex<T>) {
+                $TraitAssign::$method_assign(self, rhs.clone());
+            }
+        }
+
+        /// Complex<T> op= &T
+        impl<T: $($Scalar)+> $TraitAssign<&T> for Complex<T> {
+            fn $method_assign(&mut self, rhs: &T) {
+                $TraitAssign::$method_assign(self, rhs.clone());
+            }
+        }
+    };
+}
+
+forward_ref_binop!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    [Num + Clone],
+    [Num + Clone]
+);
+forward_ref_binop!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    [Num + Clone],
+    [Num + Clone]
+);
+forward_ref_binop!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    [ComplexPart],
+    [Num + Clone]
+);
+forward_ref_binop!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    [ComplexPart],
+    [Num + Clone]
+);
+
+forward_ref_binop!(
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    [ComplexPart],
+    [Num + Clone]
+);
+
+/// -&Complex<T>
+impl<T: Clone + Neg<Output = T>> Neg for &Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        -self.clone()
+    }
+}
+
+// Copy types
+
+/// Implements the compound assignment operators and every mix of owned and borrowed operands for a
+/// Copy type, by forwarding to its owned binary operators.
+///
+/// Used by the integer rings, which only have to implement the owned operators themselves.
+macro_rules! forward_copy_ops {
+    (impl $params:tt $Type:ty: $($Trait:ident::$method:ident, $TraitAssign:ident::$method_assign:ident;)+) => {$(
+        $crate::overloading::forward_copy_ops!(@one $params $Type, $Trait::$method, $TraitAssign::$method_assign);
+    )+};
+    (@one [$($params:tt)*] $Type:ty, $Trait:ident::$method:ident, $TraitAssign:ident::$method_assign:ident) => {
+        impl<$($params)*> $TraitAssign for $Type {
+            fn $method_assign(&mut self, rhs: $Type) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+
+        impl<$($params)*> $TraitAssign<&$Type> for $Type {
+            fn $method_assign(&mut self, rhs: &$Type) {
+                *self = $Trait::$method(*self, *rhs);
+            }
+        }
+
+        impl<$($params)*> $Trait<&$Type> for $Type {
+            type Output = $Type;
+
+            fn $method(self, rhs: &$Type) -> $Type {
+                $Trait::$method(self, *rhs)
+            }
+        }
+
+        impl<$($params)*> $Trait<$Type> for &$Type {
+            type Output = $Type;
+
+            fn $method(self, rhs: $Type) -> $Type {
+                $Trait::$method(*self, rhs)
+            }
+        }
+
+        impl<$($params)*> $Trait<&$Type> for &$Type {
+            type Output = $Type;
+
+            fn $method(self, rhs: &$Type) -> $Type {
+                $Trait::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+pub(crate) use forward_copy_ops;
+
+// Clone types
+
+/// Implements the compound assignment operators and every mix of owned and borrowed operands for a
+/// type that is expensive to copy, by forwarding to its binary operators on two references.
+///
+/// Used by the exact rational numbers, whose operators only need to borrow their operands.
+#[cfg(feature = "rational")]
+macro_rules! forward_clone_ops {
+    (impl $Type:ty: $($Trait:ident::$method:ident, $TraitAssign:ident::$method_assign:ident;)+) => {$(
+        impl $TraitAssign for $Type {
+            fn $method_assign(&mut self, rhs: $Type) {
+                *self = $Trait::$method(&*self, &rhs);
+            }
+        }
+
+        impl $TraitAssign<&$Type> for $Type {
+            fn $method_assign(&mut self, rhs: &$Type) {
+                *self = $Trait::$method(&*self, rhs);
+            }
+        }
+
+        impl $Trait for $Type {
+            type Output = $Type;
+
+            fn $method(self, rhs: $Type) -> $Type {
+                $Trait::$method(&self, &rhs)
+            }
+        }
+
+        impl $Trait<&$Type> for $Type {
+            type Output = $Type;
+
+            fn $method(self, rhs: &$Type) -> $Type {
+                $Trait::$method(&self, rhs)
+            }
+        }
+
+        impl $Trait<$Type> for &$Type {
+            type Output = $Type;
+
+            fn $method(self, rhs: $Type) -> $Type {
+                $Trait::$method(self, &rhs)
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "rational")]
+pub(crate) use forward_clone_ops;
+
+// Integer scalars
+
+/// Implements the arithmetic operators between a Complex of a float type and an integer type, on
+/// both sides. The integer is converted to the float type first, which can round very large values.
+macro_rules! integer_scalar_ops {
+    ($Float:ty, $($Int:ty),*) => {$(
+        /// Complex<F> + I
+        impl Add<$Int> for Complex<$Float> {
+            type Output = Complex<$Float>;
+
+            fn add(self, rhs: $Int) -> Complex<$Float> {
+                self + rhs as $Float
+            }
+        }
+
+        /// I + Complex<F>
+        impl Add<Complex<$Float>> for $Int {
+            type Output = Complex<$Float>;
+
+            fn add(self, rhs: Complex<$Float>) -> Complex<$Float> {
+                self as $Float + rhs
+            }
+        }
+
+        /// Complex<F> += I
+        impl AddAssign<$Int> for Complex<$Float> {
+            fn add_assign(&mut self, rhs: $Int) {
+                *self += rhs as $Float;
+            }
+        }
+
+        /// Complex<F> - I
+        impl Sub<$Int> for Complex<$Float> {
+            type Output = Complex<$Float>;
+
+            fn sub(self, rhs: $Int) -> Complex<$Float> {
+                self - rhs as $Float
+            }
+        }
+
+        /// I - Complex<F>
+        impl Sub<Complex<$Float>> for $Int {
+            type Output = Complex<$Float>;
+
+            fn sub(self, rhs: Complex<$Float>) -> Complex<$Float> {
+                self as $Float - rhs
+            }
+        }
+
+        /// Complex<F> -= I
+        impl SubAssign<$Int> for Complex<$Float> {
+            fn sub_assign(&mut self, rhs: $Int) {
+                *self -= rhs as $Float;
+            }
+        }
+
+        /// Complex<F> * I
+        impl Mul<$Int> for Complex<$Float> {
+            type Output = Complex<$Float>;
+
+            fn mul(self, rhs: $Int) -> Complex<$Float> {
+                self * rhs as $Float
+            }
+        }
+
+        /// I * Complex<F>
+        impl Mul<Complex<$Float>> for $Int {
+            type Output = Complex<$Float>;
+
+            fn mul(self, rhs: Complex<$Float>) -> Complex<$Float> {
+                self as $Float * rhs
+            }
+        }
+
+        /// Complex<F> *= I
+        impl MulAssign<$Int> for Complex<$Float> {
+            fn mul_assign(&mut self, rhs: $Int) {
+                *self *= rhs as $Float;
+            }
+        }
+
+        /// Complex<F> / I
+        impl Div<$Int> for Complex<$Float> {
+            type Output = Complex<$Float>;
+
+            fn div(self, rhs: $Int) -> Complex<$Float> {
+                self / rhs as $Float
+            }
+        }
+
+        /// I / Complex<F>
+        impl Div<Complex<$Float>> for $Int {
+            type Output = Complex<$Float>;
+
+            fn div(self, rhs: Complex<$Float>) -> Complex<$Float> {
+                self as $Float / rhs
+            }
+        }
+
+        /// Complex<F> /= I
+        impl DivAssign<$Int> for Complex<$Float> {
+            fn div_assign(&mut self, rhs: $Int) {
+                *self /= rhs as $Float;
+            }
+        }
+    )*};
+}
+
+integer_scalar_ops!(f32, i32, i64, u32);
+integer_scalar_ops!(f64, i32, i64, u32);
+
+// Polar
+
+/// Polar<T> * Polar<T>
+impl<T: Float> Mul<Polar<T>> for Polar<T> {
+    type Output = Polar<T>;
+
+    fn mul(self, rhs: Polar<T>) -> Polar<T> {
+        Polar::new(
+            self.modulus * rhs.modulus,
+            normalize_angle(self.angle + rhs.angle),
+        )
+    }
+}
+
+/// Polar<T> *= Polar<T>
+impl<T: Float> MulAssign<Polar<T>> for Polar<T> {
+    fn mul_assign(&mut self, rhs: Polar<T>) {
+        *self = Polar::new(
+            self.modulus * rhs.modulus,
+            normalize_angle(self.angle + rhs.angle),
+        );
+    }
+}
+
+/// Polar<T> / Polar<T>
+impl<T: Float> Div<Polar<T>> for Polar<T> {
+    type Output = Polar<T>;
+
+    fn div(self, rhs: Polar<T>) -> Polar<T> {
+        Polar::new(
+            self.modulus / rhs.modulus,
+            normalize_angle(self.angle - rhs.angle),
+        )
+    }
+}
+
+/// Polar<T> /= Polar<T>
+impl<T: Float> DivAssign<Polar<T>> for Polar<T> {
+    fn div_assign(&mut self, rhs: Polar<T>) {
+        *self = Polar::new(
+            self.modulus / rhs.modulus,
+            normalize_angle(self.angle - rhs.angle),
         );
     }
 }
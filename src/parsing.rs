@@ -0,0 +1,235 @@
+use crate::Complex;
+use core::fmt::{Display, Formatter, Result};
+use core::ops::Neg;
+use core::str::FromStr;
+use num_traits::{Float, Num};
+
+/// The reason a string could not be parsed into a [`Complex`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseComplexErrorKind {
+    /// The string contains nothing but whitespace.
+    Empty,
+    /// A term could not be parsed as a number.
+    InvalidNumber,
+    /// Two terms were not separated by `+` or `-`.
+    MissingOperator,
+    /// An operator was not followed by a term.
+    MissingTerm,
+    /// The string contains two real or two imaginary parts.
+    DuplicatePart,
+    /// The radix is outside of 2..=18, above 18 the letter i is a digit.
+    InvalidRadix,
+}
+
+/// Error returned when parsing a [`Complex`] from a string fails.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseComplexError {
+    kind: ParseComplexErrorKind,
+    position: usize,
+}
+
+impl ParseComplexError {
+    fn new(kind: ParseComplexErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Returns what went wrong while parsing.
+    pub fn kind(&self) -> ParseComplexErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the input at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for ParseComplexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let description = match self.kind {
+            ParseComplexErrorKind::Empty => "cannot parse complex number from empty string",
+            ParseComplexErrorKind::InvalidNumber => "invalid number",
+            ParseComplexErrorKind::MissingOperator => "expected '+' or '-'",
+            ParseComplexErrorKind::MissingTerm => "expected a term after operator",
+            ParseComplexErrorKind::DuplicatePart => "real or imaginary part given twice",
+            ParseComplexErrorKind::InvalidRadix => "radix must be between 2 and 18",
+        };
+        write!(f, "{} at position {}", description, self.position)
+    }
+}
+
+impl core::error::Error for ParseComplexError {}
+
+/// A single signed term of a complex number, like `-4.5`, `2e3i` or `-i`.
+struct Term<T> {
+    value: T,
+    imaginary: bool,
+}
+
+fn is_operator(byte: u8) -> bool {
+    byte == b'+' || byte == b'-'
+}
+
+fn skip_whitespace(bytes: &[u8], mut position: usize) -> usize {
+    while position < bytes.len() && bytes[position].is_ascii_whitespace() {
+        position += 1;
+    }
+    position
+}
+
+/// Parses the term starting at `start`, returning it together with the position right after it.
+fn parse_term<T: Num + Clone + Neg<Output = T>>(
+    input: &str,
+    start: usize,
+    radix: u32,
+) -> core::result::Result<(Term<T>, usize), ParseComplexError> {
+    let bytes = input.as_bytes();
+    let mut end = start;
+
+    if end < bytes.len() && is_operator(bytes[end]) {
+        end += 1;
+    }
+    let body_start = end;
+
+    // Signs are only part of the term when they belong to an exponent, like in 1e-5, and only
+    // radix 10 has exponents
+    let numeric = radix == 10
+        && body_start < bytes.len()
+        && (bytes[body_start].is_ascii_digit() || bytes[body_start] == b'.');
+    while end < bytes.len() {
+        let byte = bytes[end];
+        let exponent_sign =
+            numeric && is_operator(byte) && (bytes[end - 1] == b'e' || bytes[end - 1] == b'E');
+        if byte.is_ascii_alphanumeric() || byte == b'.' || exponent_sign {
+            end += 1;
+        } else {
+            break;
+        }
+    }
+
+    if end == body_start {
+        return Err(ParseComplexError::new(
+            ParseComplexErrorKind::MissingTerm,
+            start,
+        ));
+    }
+
+    let imaginary = bytes[end - 1] == b'i' || bytes[end - 1] == b'j';
+    let literal = if imaginary {
+        &input[start..end - 1]
+    } else {
+        &input[start..end]
+    };
+
+    // The float parsers take e as a power of ten and p as a power of two in radix 16, only the
+    // first is allowed and only in radix 10
+    let exponent = |c: char| matches!(c, 'e' | 'E' | 'p' | 'P') && !c.is_digit(radix);
+    if radix != 10 && literal.chars().any(exponent) {
+        return Err(ParseComplexError::new(
+            ParseComplexErrorKind::InvalidNumber,
+            start,
+        ));
+    }
+
+    let value = match literal {
+        // A lone i or j has an implicit coefficient of one
+        "" | "+" => T::one(),
+        "-" => -T::one(),
+        // Only radix 10 accepts a leading plus sign by itself
+        _ => T::from_str_radix(literal.strip_prefix('+').unwrap_or(literal), radix)
+            .map_err(|_| ParseComplexError::new(ParseComplexErrorKind::InvalidNumber, start))?,
+    };
+
+    Ok((Term { value, imaginary }, end))
+}
+
+/// Parses a [`Complex`] written in the given radix.
+pub(crate) fn parse_complex<T: Num + Clone + Neg<Output = T>>(
+    input: &str,
+    radix: u32,
+) -> core::result::Result<Complex<T>, ParseComplexError> {
+    if !(2..=18).contains(&radix) {
+        return Err(ParseComplexError::new(
+            ParseComplexErrorKind::InvalidRadix,
+            0,
+        ));
+    }
+
+    let bytes = input.as_bytes();
+    let mut position = skip_whitespace(bytes, 0);
+    if position == bytes.len() {
+        return Err(ParseComplexError::new(
+            ParseComplexErrorKind::Empty,
+            position,
+        ));
+    }
+
+    let mut real: Option<T> = None;
+    let mut imag: Option<T> = None;
+    let mut first = true;
+
+    while position < bytes.len() {
+        let term_start = position;
+        let (term, end) = if first {
+            parse_term::<T>(input, position, radix)?
+        } else {
+            // Every term after the first one is preceded by an operator, checked after the
+            // previous term
+            let negative = bytes[position] == b'-';
+            let term_position = skip_whitespace(bytes, position + 1);
+            // The operator is the sign of the term, a second one like in 3 - -4i is rejected
+            if term_position == bytes.len() || is_operator(bytes[term_position]) {
+                return Err(ParseComplexError::new(
+                    ParseComplexErrorKind::MissingTerm,
+                    position,
+                ));
+            }
+            let (mut term, end) = parse_term::<T>(input, term_position, radix)?;
+            if negative {
+                term.value = -term.value;
+            }
+            (term, end)
+        };
+
+        // A term ends at whitespace or an operator, anything else is a stray character
+        if end < bytes.len() && !bytes[end].is_ascii_whitespace() && !is_operator(bytes[end]) {
+            return Err(ParseComplexError::new(
+                ParseComplexErrorKind::InvalidNumber,
+                end,
+            ));
+        }
+        let next = skip_whitespace(bytes, end);
+        if next < bytes.len() && !is_operator(bytes[next]) {
+            return Err(ParseComplexError::new(
+                ParseComplexErrorKind::MissingOperator,
+                next,
+            ));
+        }
+
+        let part = if term.imaginary { &mut imag } else { &mut real };
+        if part.is_some() {
+            return Err(ParseComplexError::new(
+                ParseComplexErrorKind::DuplicatePart,
+                term_start,
+            ));
+        }
+        *part = Some(term.value);
+
+        first = false;
+        position = next;
+    }
+
+    Ok(Complex::new(
+        real.unwrap_or(T::zero()),
+        imag.unwrap_or(T::zero()),
+    ))
+}
+
+/// Parses strings like `3 + 4i`, `3-4j`, `-i`, `2.5e-3` or `inf + nani`.
+impl<T: Float> FromStr for Complex<T> {
+    type Err = ParseComplexError;
+
+    fn from_str(input: &str) -> core::result::Result<Self, Self::Err> {
+        parse_complex(input, 10)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Complex, Numbers};
+use num_traits::Float;
+
+/// Struct representing a complex number in polar form
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Polar<T: Float> {
+    pub(crate) modulus: T,
+    pub(crate) angle: T,
+}
+
+impl<T: Float> Polar<T> {
+    /// Creates a new [`Polar`].
+    ///
+    /// The angle is stored as given, use [`Polar::normalize`] to bring it into (-PI, PI].
+    pub fn new(modulus: T, angle: T) -> Self {
+        Self { modulus, angle }
+    }
+
+    /// Returns the modulus of this [`Polar`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Polar;
+    ///
+    /// let p = Polar::new(2.0, 0.5);
+    /// assert_eq!(p.modulus(), 2.0);
+    /// ```
+    pub fn modulus(self) -> T {
+        self.modulus
+    }
+
+    /// Returns the angle of this [`Polar`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Polar;
+    ///
+    /// let p = Polar::new(2.0, 0.5);
+    /// assert_eq!(p.angle(), 0.5);
+    /// ```
+    pub fn angle(self) -> T {
+        self.angle
+    }
+
+    /// Returns this [`Polar`] with a non-negative modulus and its angle on the interval (-PI, PI].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Polar;
+    /// use std::f64::consts::PI;
+    ///
+    /// let p = Polar::new(-2.0, PI / 2.0);
+    /// assert_eq!(p.normalize(), Polar::new(2.0, -PI / 2.0));
+    /// ```
+    pub fn normalize(self) -> Self {
+        if self.modulus < T::zero() {
+            Self::new(-self.modulus, normalize_angle(self.angle + T::pi()))
+        } else {
+            Self::new(self.modulus, normalize_angle(self.angle))
+        }
+    }
+
+    /// Returns the multiplicative inverse of this [`Polar`].
+    pub fn inv(self) -> Self {
+        Self::new(T::recip(self.modulus), normalize_angle(-self.angle))
+    }
+
+    /// Returns the conjugate of this [`Polar`].
+    pub fn conj(self) -> Self {
+        Self::new(self.modulus, normalize_angle(-self.angle))
+    }
+
+    /// Returns this [`Polar`] raised to an integer power.
+    pub fn powi(self, exponent: i64) -> Self {
+        let exponent = T::from(exponent).unwrap();
+        Self::new(
+            T::powf(self.modulus, exponent),
+            normalize_angle(self.angle * exponent),
+        )
+    }
+
+    /// Returns this [`Polar`] raised to a power using De Moivre's formula.
+    pub fn powf(self, exponent: T) -> Self {
+        Self::new(
+            T::powf(self.modulus, exponent),
+            normalize_angle(self.angle * exponent),
+        )
+    }
+}
+
+/// Maps an angle onto the interval (-PI, PI].
+pub(crate) fn normalize_angle<T: Float>(angle: T) -> T {
+    if !angle.is_finite() || (angle > -T::pi() && angle <= T::pi()) {
+        return angle;
+    }
+
+    let turn = T::two() * T::pi();
+    let normalized = angle - turn * T::ceil((angle - T::pi()) / turn);
+    // Rounding can push the result just past either end of the interval
+    if normalized <= -T::pi() {
+        normalized + turn
+    } else if normalized > T::pi() {
+        normalized - turn
+    } else {
+        normalized
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// Creates a new [`Complex`] from its modulus and angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let z = Complex::from_polar(2.0, 0.0);
+    /// assert_eq!(z, Complex::new(2.0, 0.0));
+    /// ```
+    pub fn from_polar(modulus: T, angle: T) -> Self {
+        Self::new(modulus * T::cos(angle), modulus * T::sin(angle))
+    }
+
+    /// Returns cos(angle) + i sin(angle), the point on the unit circle at the given angle.
+    pub fn cis(angle: T) -> Self {
+        Self::from_polar(T::one(), angle)
+    }
+}
+
+/// Complex<T> -> Polar<T>
+impl<T: Float> From<Complex<T>> for Polar<T> {
+    fn from(z: Complex<T>) -> Polar<T> {
+        // arg gives -PI below the negative real axis, which is outside the normalized interval
+        Polar::new(Complex::abs(z), normalize_angle(Complex::arg(z)))
+    }
+}
+
+/// Polar<T> -> Complex<T>
+impl<T: Float> From<Polar<T>> for Complex<T> {
+    fn from(p: Polar<T>) -> Complex<T> {
+        Complex::from_polar(p.modulus, p.angle)
+    }
+}
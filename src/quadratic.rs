@@ -0,0 +1,503 @@
+//! Eisenstein integers and the rings Z[√D] of imaginary quadratic integers.
+//!
+//! [`Eisenstein`] is a + bω with ω = (-1 + i√3) / 2 a primitive cube root of unity, and
+//! [`QuadraticInt<D>`] is a + b√D for a negative squarefree D, so `QuadraticInt<-2>` is Z[√-2] and
+//! `QuadraticInt<-1>` holds the same numbers as [`GaussianInt`](crate::gaussian::GaussianInt).
+//! Both convert to [`Complex<f64>`] for plotting.
+use crate::Complex;
+use crate::gaussian::{GaussianInt, round_div};
+use crate::overloading::forward_copy_ops;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use num_traits::Float;
+
+/// Eisenstein integer a + bω, where ω = (-1 + i√3) / 2 satisfies ω² = -1 - ω.
+///
+/// The Eisenstein integers form a Euclidean ring with six units, the sixth roots of unity. The
+/// arithmetic panics on overflow in debug builds and wraps in release builds, like `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::quadratic::Eisenstein;
+///
+/// let omega = Eisenstein::OMEGA;
+///
+/// assert_eq!(omega * omega, Eisenstein::new(-1, -1));
+/// assert_eq!(omega * omega * omega, Eisenstein::ONE);
+/// assert_eq!(Eisenstein::new(2, 1).norm(), 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Eisenstein {
+    a: i64,
+    b: i64,
+}
+
+impl Eisenstein {
+    /// Zero, 0 + 0ω
+    pub const ZERO: Self = Self::new(0, 0);
+    /// One, 1 + 0ω
+    pub const ONE: Self = Self::new(1, 0);
+    /// The cube root of unity ω, 0 + 1ω
+    pub const OMEGA: Self = Self::new(0, 1);
+    /// The six units, the powers of -ω² = 1 + ω in counterclockwise order starting at 1.
+    pub const UNITS: [Self; 6] = [
+        Self::new(1, 0),
+        Self::new(1, 1),
+        Self::new(0, 1),
+        Self::new(-1, 0),
+        Self::new(-1, -1),
+        Self::new(0, -1),
+    ];
+
+    /// Creates a new [`Eisenstein`] integer a + bω.
+    pub const fn new(a: i64, b: i64) -> Self {
+        Self { a, b }
+    }
+
+    /// Returns the coefficients a and b of a + bω.
+    pub fn coefficients(self) -> (i64, i64) {
+        (self.a, self.b)
+    }
+
+    /// Returns the conjugate a + bω̄ = (a - b) - bω.
+    pub fn conj(self) -> Self {
+        Self::new(self.a - self.b, -self.b)
+    }
+
+    /// Returns the norm a² - ab + b², the square of the absolute value.
+    ///
+    /// The norm is computed in `u128` so it can't overflow.
+    pub fn norm(self) -> u128 {
+        let (a, b) = (self.a.unsigned_abs() as u128, self.b.unsigned_abs() as u128);
+        // |a|, |b| <= 2^63, so every term is at most 2^126 and the sum fits
+        if (self.a < 0) != (self.b < 0) {
+            a * a + a * b + b * b
+        } else {
+            a * a + b * b - a * b
+        }
+    }
+
+    /// Returns true for the six units, the Eisenstein integers with norm 1.
+    pub fn is_unit(self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Returns this [`Eisenstein`] integer multiplied by each of the six units, starting with
+    /// itself.
+    pub fn associates(self) -> [Self; 6] {
+        Self::UNITS.map(|unit| self * unit)
+    }
+
+    /// Divides with a remainder, returning the quotient and the remainder.
+    ///
+    /// The exact quotient is rounded to the nearest integer in both coefficients, so the remainder
+    /// r = self - q rhs has N(r) <= 3 N(rhs) / 4.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the quotient overflows, which takes coefficients close to the
+    /// limits of `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::quadratic::Eisenstein;
+    ///
+    /// let z = Eisenstein::new(17, -5);
+    /// let w = Eisenstein::new(3, 4);
+    /// let (quotient, remainder) = z.div_rem(w);
+    ///
+    /// assert_eq!(quotient * w + remainder, z);
+    /// assert!(remainder.norm() < w.norm());
+    /// ```
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(
+            rhs != Self::ZERO,
+            "attempt to divide an Eisenstein integer by zero"
+        );
+        const OVERFLOW: &str = "overflow in Eisenstein integer division";
+        let narrow = |x: Option<i128>| x.and_then(|x| i64::try_from(x).ok()).expect(OVERFLOW);
+        let norm = i128::try_from(rhs.norm()).expect(OVERFLOW);
+        // self / rhs = self conj(rhs) / N(rhs)
+        let (c, d) = (rhs.a as i128, rhs.b as i128);
+        let (a, b) = wide_eisenstein_product(self, (c - d, -d)).expect(OVERFLOW);
+        let (a, b) = (round_div(a, norm), round_div(b, norm));
+        let quotient = Self::new(narrow(Some(a)), narrow(Some(b)));
+        let (c, d) = wide_eisenstein_product(quotient, (c, d)).expect(OVERFLOW);
+        let remainder = Self::new(
+            narrow((self.a as i128).checked_sub(c)),
+            narrow((self.b as i128).checked_sub(d)),
+        );
+        (quotient, remainder)
+    }
+}
+
+/// Multiplies an Eisenstein integer by the coefficients c + dω in `i128`, returning `None` on
+/// overflow.
+fn wide_eisenstein_product(lhs: Eisenstein, (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let (a, b) = (lhs.a as i128, lhs.b as i128);
+    // |c|, |d| < 2^64 here, so every product of two coefficients fits
+    Some((
+        (a * c).checked_sub(b * d)?,
+        (a * d).checked_add(b * c)?.checked_sub(b * d)?,
+    ))
+}
+
+/// Imaginary quadratic integer a + b√D in the ring Z[√D], for a negative squarefree D.
+///
+/// Z[√D] is the full ring of integers of Q(√D) when D is 2 or 3 mod 4. For D = 1 mod 4 the ring
+/// of integers is larger, with [`Eisenstein`] covering D = -3. Only D = -1 and D = -2 give a
+/// Euclidean ring, for the others [`QuadraticInt::div_rem`] can fail.
+///
+/// A D that is not negative and squarefree fails to compile:
+///
+/// ```compile_fail
+/// let z = ccmath::quadratic::QuadraticInt::<-4>::new(1, 1);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::quadratic::QuadraticInt;
+///
+/// type Z2 = QuadraticInt<-2>;
+///
+/// let z = Z2::new(1, 1);
+///
+/// assert_eq!(z * z, Z2::new(-1, 2));
+/// assert_eq!(z.norm(), 3);
+/// assert_eq!(z * z.conj(), Z2::new(3, 0));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct QuadraticInt<const D: i64> {
+    a: i64,
+    b: i64,
+}
+
+/// Returns true if n has no square factor besides 1.
+const fn is_squarefree(n: i64) -> bool {
+    let n = n.unsigned_abs();
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p * p) {
+            return false;
+        }
+        p += 1;
+    }
+    true
+}
+
+impl<const D: i64> QuadraticInt<D> {
+    /// Evaluated by every constructor, so an invalid D is a compile error.
+    const VALID_D: () = assert!(
+        D < 0 && is_squarefree(D),
+        "D has to be negative and squarefree"
+    );
+
+    /// Zero, 0 + 0√D
+    pub const ZERO: Self = Self::new(0, 0);
+    /// One, 1 + 0√D
+    pub const ONE: Self = Self::new(1, 0);
+    /// The square root of D, 0 + 1√D
+    pub const SQRT_D: Self = Self::new(0, 1);
+
+    /// Creates a new [`QuadraticInt`] a + b√D.
+    pub const fn new(a: i64, b: i64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_D;
+        Self { a, b }
+    }
+
+    /// Returns the coefficients a and b of a + b√D.
+    pub fn coefficients(self) -> (i64, i64) {
+        (self.a, self.b)
+    }
+
+    /// Returns the conjugate a - b√D.
+    pub fn conj(self) -> Self {
+        Self::new(self.a, -self.b)
+    }
+
+    /// Returns the norm a² - D b², the square of the absolute value.
+    ///
+    /// The norm is computed in `u128`, which can't overflow for |D| <= 2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the norm doesn't fit in a `u128`, which takes |D| >= 3 and a coefficient b close
+    /// to the limits of `i64`.
+    pub fn norm(self) -> u128 {
+        let (a, b) = (self.a.unsigned_abs() as u128, self.b.unsigned_abs() as u128);
+        (D.unsigned_abs() as u128 * b)
+            .checked_mul(b)
+            .and_then(|db2| db2.checked_add(a * a))
+            .expect("overflow in quadratic integer norm")
+    }
+
+    /// Returns true if Z[√D] is a Euclidean ring under the norm, which is only the case for D = -1
+    /// and D = -2.
+    pub const fn is_euclidean() -> bool {
+        D == -1 || D == -2
+    }
+
+    /// Returns true for the units, the elements with norm 1.
+    pub fn is_unit(self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Returns the units, ±1 and also ±√-1 for D = -1.
+    pub fn units() -> impl Iterator<Item = Self> {
+        [(1, 0), (0, 1), (-1, 0), (0, -1)]
+            .into_iter()
+            .map(|(a, b)| Self::new(a, b))
+            .filter(|unit| unit.is_unit())
+    }
+
+    /// Divides with a remainder smaller in norm than `rhs`, returning the quotient and the
+    /// remainder.
+    ///
+    /// The quotient is the exact quotient rounded to the nearest integer in both coefficients.
+    /// That always gives a small enough remainder in the Euclidean rings, see
+    /// [`QuadraticInt::is_euclidean`], elsewhere this returns `None` when it doesn't.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the quotient overflows, which takes coefficients close to the
+    /// limits of `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::quadratic::QuadraticInt;
+    ///
+    /// let (z, w) = (QuadraticInt::<-2>::new(13, 4), QuadraticInt::<-2>::new(3, -2));
+    /// let (quotient, remainder) = z.div_rem(w).unwrap();
+    /// assert_eq!(quotient * w + remainder, z);
+    ///
+    /// // Z[√-5] isn't Euclidean, 1 + √-5 leaves no remainder smaller than 2 when divided by 2
+    /// let (z, w) = (QuadraticInt::<-5>::new(1, 1), QuadraticInt::<-5>::new(2, 0));
+    /// assert_eq!(z.div_rem(w), None);
+    /// ```
+    pub fn div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        let (quotient, remainder) = self.div_rem_rounded(rhs);
+        (remainder.norm() < rhs.norm()).then_some((quotient, remainder))
+    }
+
+    /// Returns the exact quotient if `rhs` divides this [`QuadraticInt`], in any of the rings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the quotient overflows like in [`QuadraticInt::div_rem`].
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (quotient, remainder) = self.div_rem_rounded(rhs);
+        (remainder == Self::ZERO).then_some(quotient)
+    }
+
+    /// Divides and rounds the exact quotient, whether or not the remainder ends up small.
+    fn div_rem_rounded(self, rhs: Self) -> (Self, Self) {
+        assert!(
+            rhs != Self::ZERO,
+            "attempt to divide a quadratic integer by zero"
+        );
+        const OVERFLOW: &str = "overflow in quadratic integer division";
+        let narrow = |x: Option<i128>| x.and_then(|x| i64::try_from(x).ok()).expect(OVERFLOW);
+        let norm = i128::try_from(rhs.norm()).expect(OVERFLOW);
+        // self / rhs = self conj(rhs) / N(rhs)
+        let (c, d) = (rhs.a as i128, rhs.b as i128);
+        let (real, root) = wide_quadratic_product(self, (c, -d)).expect(OVERFLOW);
+        let (real, root) = (round_div(real, norm), round_div(root, norm));
+        let quotient = Self::new(narrow(Some(real)), narrow(Some(root)));
+        let (c, d) = wide_quadratic_product(quotient, (c, d)).expect(OVERFLOW);
+        let remainder = Self::new(
+            narrow((self.a as i128).checked_sub(c)),
+            narrow((self.b as i128).checked_sub(d)),
+        );
+        (quotient, remainder)
+    }
+}
+
+/// Multiplies a quadratic integer by the coefficients c + d√D in `i128`, returning `None` on
+/// overflow.
+fn wide_quadratic_product<const D: i64>(
+    lhs: QuadraticInt<D>,
+    (c, d): (i128, i128),
+) -> Option<(i128, i128)> {
+    let (a, b) = (lhs.a as i128, lhs.b as i128);
+    Some((
+        (a * c).checked_add((D as i128).checked_mul(b * d)?)?,
+        (a * d).checked_add(b * c)?,
+    ))
+}
+
+// Operators
+
+/// Eisenstein + Eisenstein
+impl Add for Eisenstein {
+    type Output = Eisenstein;
+
+    fn add(self, rhs: Eisenstein) -> Eisenstein {
+        Eisenstein::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+/// Eisenstein - Eisenstein
+impl Sub for Eisenstein {
+    type Output = Eisenstein;
+
+    fn sub(self, rhs: Eisenstein) -> Eisenstein {
+        Eisenstein::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+/// Eisenstein * Eisenstein, using ω² = -1 - ω
+impl Mul for Eisenstein {
+    type Output = Eisenstein;
+
+    fn mul(self, rhs: Eisenstein) -> Eisenstein {
+        let (a, b, c, d) = (self.a, self.b, rhs.a, rhs.b);
+        Eisenstein::new(a * c - b * d, a * d + b * c - b * d)
+    }
+}
+
+/// Eisenstein / Eisenstein, the rounded quotient of [`Eisenstein::div_rem`]
+impl Div for Eisenstein {
+    type Output = Eisenstein;
+
+    fn div(self, rhs: Eisenstein) -> Eisenstein {
+        self.div_rem(rhs).0
+    }
+}
+
+/// Eisenstein % Eisenstein, the remainder of [`Eisenstein::div_rem`]
+impl Rem for Eisenstein {
+    type Output = Eisenstein;
+
+    fn rem(self, rhs: Eisenstein) -> Eisenstein {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_copy_ops!(impl[] Eisenstein:
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+    Rem::rem, RemAssign::rem_assign;
+);
+
+/// -Eisenstein
+impl Neg for Eisenstein {
+    type Output = Eisenstein;
+
+    fn neg(self) -> Eisenstein {
+        Eisenstein::new(-self.a, -self.b)
+    }
+}
+
+/// QuadraticInt<D> + QuadraticInt<D>
+impl<const D: i64> Add for QuadraticInt<D> {
+    type Output = QuadraticInt<D>;
+
+    fn add(self, rhs: QuadraticInt<D>) -> QuadraticInt<D> {
+        QuadraticInt::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+/// QuadraticInt<D> - QuadraticInt<D>
+impl<const D: i64> Sub for QuadraticInt<D> {
+    type Output = QuadraticInt<D>;
+
+    fn sub(self, rhs: QuadraticInt<D>) -> QuadraticInt<D> {
+        QuadraticInt::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+/// QuadraticInt<D> * QuadraticInt<D>
+impl<const D: i64> Mul for QuadraticInt<D> {
+    type Output = QuadraticInt<D>;
+
+    fn mul(self, rhs: QuadraticInt<D>) -> QuadraticInt<D> {
+        let (a, b, c, d) = (self.a, self.b, rhs.a, rhs.b);
+        QuadraticInt::new(a * c + D * b * d, a * d + b * c)
+    }
+}
+
+forward_copy_ops!(impl[const D: i64] QuadraticInt<D>:
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+);
+
+/// -QuadraticInt<D>
+impl<const D: i64> Neg for QuadraticInt<D> {
+    type Output = QuadraticInt<D>;
+
+    fn neg(self) -> QuadraticInt<D> {
+        QuadraticInt::new(-self.a, -self.b)
+    }
+}
+
+// Conversions
+
+/// i64 -> Eisenstein
+impl From<i64> for Eisenstein {
+    fn from(a: i64) -> Eisenstein {
+        Eisenstein::new(a, 0)
+    }
+}
+
+/// Eisenstein -> Complex<f64>, with ω = -1/2 + i√3/2
+impl From<Eisenstein> for Complex<f64> {
+    fn from(z: Eisenstein) -> Complex<f64> {
+        let (a, b) = (z.a as f64, z.b as f64);
+        Complex::new(a - b / 2f64, b * Float::sqrt(3f64) / 2f64)
+    }
+}
+
+/// i64 -> QuadraticInt<D>
+impl<const D: i64> From<i64> for QuadraticInt<D> {
+    fn from(a: i64) -> QuadraticInt<D> {
+        QuadraticInt::new(a, 0)
+    }
+}
+
+/// QuadraticInt<D> -> Complex<f64>, with √D = i√|D|
+impl<const D: i64> From<QuadraticInt<D>> for Complex<f64> {
+    fn from(z: QuadraticInt<D>) -> Complex<f64> {
+        let root = Float::sqrt(D.unsigned_abs() as f64);
+        Complex::new(z.a as f64, z.b as f64 * root)
+    }
+}
+
+/// GaussianInt -> QuadraticInt<-1>
+impl From<GaussianInt> for QuadraticInt<-1> {
+    fn from(z: GaussianInt) -> QuadraticInt<-1> {
+        QuadraticInt::new(z.real(), z.imag())
+    }
+}
+
+/// QuadraticInt<-1> -> GaussianInt
+impl From<QuadraticInt<-1>> for GaussianInt {
+    fn from(z: QuadraticInt<-1>) -> GaussianInt {
+        GaussianInt::new(z.a, z.b)
+    }
+}
+
+/// Writes `a + bω`, or `a - bω` for a negative b
+impl Display for Eisenstein {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let operator = if self.b < 0 { "-" } else { "+" };
+        write!(f, "{} {} {}ω", self.a, operator, self.b.unsigned_abs())
+    }
+}
+
+/// Writes `a + b√D`, or `a - b√D` for a negative b, like `1 - 2√-5`
+impl<const D: i64> Display for QuadraticInt<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let operator = if self.b < 0 { "-" } else { "+" };
+        write!(f, "{} {} {}√{}", self.a, operator, self.b.unsigned_abs(), D)
+    }
+}
@@ -0,0 +1,342 @@
+//! Exact complex rational numbers, the complex numbers a + bi with rational a and b.
+//!
+//! Available with the `rational` feature. The parts are arbitrary precision fractions from
+//! `num-rational`, so [`ComplexRational`] never rounds or overflows, which makes it useful to check
+//! the floating point functions of [`Complex`] against exact results.
+use crate::Complex;
+use crate::gaussian::GaussianInt;
+use crate::overloading::forward_clone_ops;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// Complex number a + bi with exact rational parts.
+///
+/// The parts are kept in lowest terms with positive denominators by the constructors and the
+/// operators. Division by zero panics, like it does for the rational numbers themselves.
+///
+/// # Examples
+///
+/// ```
+/// use ccmath::Complex;
+/// use ccmath::rational::ComplexRational;
+///
+/// let z = ComplexRational::from(Complex::new(3, 4));
+///
+/// assert_eq!(z.inv(), ComplexRational::from_fractions((3, 25), (-4, 25)));
+/// assert_eq!(z.powi(2), ComplexRational::from(Complex::new(-7, 24)));
+/// assert_eq!(z.inv().to_string(), "3/25 - (4/25)i");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ComplexRational(pub Complex<BigRational>);
+
+impl ComplexRational {
+    /// Creates a new [`ComplexRational`], reducing both parts to lowest terms.
+    pub fn new(real: BigRational, imag: BigRational) -> Self {
+        Self(Complex::new(real.reduced(), imag.reduced()))
+    }
+
+    /// Creates a new [`ComplexRational`] from the numerators and denominators of both parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a denominator is zero.
+    pub fn from_fractions(real: (i64, i64), imag: (i64, i64)) -> Self {
+        let fraction = |(numerator, denominator): (i64, i64)| {
+            BigRational::new(numerator.into(), denominator.into())
+        };
+        Self(Complex::new(fraction(real), fraction(imag)))
+    }
+
+    /// Converts a [`Complex<f64>`] exactly, returning `None` if a part isn't finite.
+    ///
+    /// Every finite float is a fraction with a power of two as its denominator, so this loses
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    /// use ccmath::rational::ComplexRational;
+    ///
+    /// let z = ComplexRational::from_complex(Complex::new(0.75, -2.5)).unwrap();
+    ///
+    /// assert_eq!(z, ComplexRational::from_fractions((3, 4), (-5, 2)));
+    /// assert_eq!(ComplexRational::from_complex(Complex::new(f64::NAN, 0.0)), None);
+    /// ```
+    pub fn from_complex(z: Complex<f64>) -> Option<Self> {
+        Some(Self(Complex::new(
+            BigRational::from_float(z.real)?,
+            BigRational::from_float(z.imag)?,
+        )))
+    }
+
+    /// Returns the imaginary unit, 0 + 1i
+    pub fn i() -> Self {
+        Self(Complex::new(BigRational::zero(), BigRational::one()))
+    }
+
+    /// Returns the real part of this [`ComplexRational`].
+    pub fn real(&self) -> &BigRational {
+        &self.0.real
+    }
+
+    /// Returns the imaginary part of this [`ComplexRational`].
+    pub fn imag(&self) -> &BigRational {
+        &self.0.imag
+    }
+
+    /// Returns the underlying [`Complex`].
+    pub fn into_inner(self) -> Complex<BigRational> {
+        self.0
+    }
+
+    /// Returns the least common denominator d of both parts, so that d z has integer parts.
+    pub fn denominator(&self) -> BigInt {
+        self.real().denom().lcm(self.imag().denom())
+    }
+
+    /// Returns this [`ComplexRational`] with both parts reduced to lowest terms.
+    ///
+    /// Only needed after building the parts with `Ratio::new_raw`, everything else keeps them
+    /// reduced.
+    pub fn reduced(&self) -> Self {
+        Self::new(self.real().clone(), self.imag().clone())
+    }
+
+    /// Returns the complex conjugate of this [`ComplexRational`].
+    pub fn conj(&self) -> Self {
+        Self(Complex::new(self.real().clone(), -self.imag()))
+    }
+
+    /// Returns the square of the absolute value of this [`ComplexRational`], a² + b².
+    pub fn square_abs(&self) -> BigRational {
+        self.real() * self.real() + self.imag() * self.imag()
+    }
+
+    /// Returns the multiplicative inverse of this [`ComplexRational`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`ComplexRational`] is zero.
+    pub fn inv(&self) -> Self {
+        assert!(
+            !self.is_zero(),
+            "attempt to invert a complex rational number of zero"
+        );
+        // 1 / z = conj(z) / |z|²
+        let square_abs = self.square_abs();
+        Self(Complex::new(
+            self.real() / &square_abs,
+            -self.imag() / &square_abs,
+        ))
+    }
+
+    /// Returns this [`ComplexRational`] raised to a power using exponentiation by squaring.
+    ///
+    /// Any number to the power of zero is one, including zero itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`ComplexRational`] is zero and the exponent is negative.
+    pub fn powi(&self, exponent: i64) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        let mut remaining = exponent.unsigned_abs();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = &result * &base;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                base = &base * &base;
+            }
+        }
+        if exponent < 0 { result.inv() } else { result }
+    }
+
+    /// Converts to a [`Complex<f64>`], rounding both parts to the nearest float.
+    ///
+    /// Parts too large for an `f64` become infinities.
+    pub fn to_complex(&self) -> Complex<f64> {
+        let to_f64 = |part: &BigRational| {
+            part.to_f64().unwrap_or(if part.is_negative() {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            })
+        };
+        Complex::new(to_f64(self.real()), to_f64(self.imag()))
+    }
+}
+
+impl Zero for ComplexRational {
+    fn zero() -> Self {
+        Self(Complex::new(BigRational::zero(), BigRational::zero()))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real().is_zero() && self.imag().is_zero()
+    }
+}
+
+impl One for ComplexRational {
+    fn one() -> Self {
+        Self(Complex::new(BigRational::one(), BigRational::zero()))
+    }
+}
+
+// Operators
+
+/// &ComplexRational + &ComplexRational
+impl Add for &ComplexRational {
+    type Output = ComplexRational;
+
+    fn add(self, rhs: &ComplexRational) -> ComplexRational {
+        ComplexRational(Complex::new(
+            self.real() + rhs.real(),
+            self.imag() + rhs.imag(),
+        ))
+    }
+}
+
+/// &ComplexRational - &ComplexRational
+impl Sub for &ComplexRational {
+    type Output = ComplexRational;
+
+    fn sub(self, rhs: &ComplexRational) -> ComplexRational {
+        ComplexRational(Complex::new(
+            self.real() - rhs.real(),
+            self.imag() - rhs.imag(),
+        ))
+    }
+}
+
+/// &ComplexRational * &ComplexRational
+impl Mul for &ComplexRational {
+    type Output = ComplexRational;
+
+    fn mul(self, rhs: &ComplexRational) -> ComplexRational {
+        let (a, b, c, d) = (self.real(), self.imag(), rhs.real(), rhs.imag());
+        ComplexRational(Complex::new(a * c - b * d, a * d + b * c))
+    }
+}
+
+/// &ComplexRational / &ComplexRational
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Div for &ComplexRational {
+    type Output = ComplexRational;
+
+    fn div(self, rhs: &ComplexRational) -> ComplexRational {
+        assert!(
+            !rhs.is_zero(),
+            "attempt to divide a complex rational number by zero"
+        );
+        // z / w = z conj(w) / |w|²
+        let square_abs = rhs.square_abs();
+        let numerator = self * &rhs.conj();
+        ComplexRational(Complex::new(
+            numerator.real() / &square_abs,
+            numerator.imag() / &square_abs,
+        ))
+    }
+}
+
+forward_clone_ops!(impl ComplexRational:
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+);
+
+/// -ComplexRational
+impl Neg for ComplexRational {
+    type Output = ComplexRational;
+
+    fn neg(self) -> ComplexRational {
+        ComplexRational(Complex::new(-self.0.real, -self.0.imag))
+    }
+}
+
+/// -&ComplexRational
+impl Neg for &ComplexRational {
+    type Output = ComplexRational;
+
+    fn neg(self) -> ComplexRational {
+        ComplexRational(Complex::new(-self.real(), -self.imag()))
+    }
+}
+
+// Conversions
+
+/// i64 -> ComplexRational
+impl From<i64> for ComplexRational {
+    fn from(real: i64) -> Self {
+        Self::from(Complex::new(real, 0))
+    }
+}
+
+/// BigRational -> ComplexRational
+impl From<BigRational> for ComplexRational {
+    fn from(real: BigRational) -> Self {
+        Self::new(real, BigRational::zero())
+    }
+}
+
+/// Complex<i64> -> ComplexRational
+impl From<Complex<i64>> for ComplexRational {
+    fn from(z: Complex<i64>) -> Self {
+        Self(Complex::new(
+            BigRational::from_integer(z.real.into()),
+            BigRational::from_integer(z.imag.into()),
+        ))
+    }
+}
+
+/// GaussianInt -> ComplexRational
+impl From<GaussianInt> for ComplexRational {
+    fn from(z: GaussianInt) -> Self {
+        Self::from(z.0)
+    }
+}
+
+/// Complex<BigRational> -> ComplexRational, reducing both parts
+impl From<Complex<BigRational>> for ComplexRational {
+    fn from(z: Complex<BigRational>) -> Self {
+        Self::new(z.real, z.imag)
+    }
+}
+
+/// ComplexRational -> Complex<BigRational>
+impl From<ComplexRational> for Complex<BigRational> {
+    fn from(z: ComplexRational) -> Complex<BigRational> {
+        z.0
+    }
+}
+
+/// &ComplexRational -> Complex<f64>, see [`ComplexRational::to_complex`]
+impl From<&ComplexRational> for Complex<f64> {
+    fn from(z: &ComplexRational) -> Complex<f64> {
+        z.to_complex()
+    }
+}
+
+/// Writes `a + bi`, or `a - bi` for a negative imaginary part, with fractions as `n/d` and the
+/// imaginary part in parentheses when it is a fraction
+impl Display for ComplexRational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let operator = if self.imag().is_negative() { "-" } else { "+" };
+        let imag = self.imag().abs();
+        if imag.is_integer() {
+            write!(f, "{} {} {}i", self.real(), operator, imag)
+        } else {
+            write!(f, "{} {} ({})i", self.real(), operator, imag)
+        }
+    }
+}
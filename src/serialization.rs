@@ -0,0 +1,83 @@
+//! Alternative serde representations of [`Complex`].
+//!
+//! By default a [`Complex`] serializes as `{ "re": ..., "im": ... }`. The modules in here can be
+//! used with `#[serde(with = "...")]` to pick a different representation for a field.
+//!
+//! # Examples
+//!
+//! ```
+//! use ccmath::Complex;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct State {
+//!     #[serde(with = "ccmath::serialization::array")]
+//!     position: Complex<f64>,
+//!     #[serde(with = "ccmath::serialization::string")]
+//!     velocity: Complex<f64>,
+//! }
+//! ```
+
+/// Serializes a [`Complex`] as the array `[re, im]`.
+pub mod array {
+    use crate::Complex;
+    use num_traits::Float;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(z: &Complex<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Float + Serialize,
+        S: Serializer,
+    {
+        [z.real, z.imag].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Complex<T>, D::Error>
+    where
+        T: Float + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let [real, imag] = <[T; 2]>::deserialize(deserializer)?;
+        Ok(Complex::new(real, imag))
+    }
+}
+
+/// Serializes a [`Complex`] as the string produced by its `Display` implementation.
+pub mod string {
+    use crate::Complex;
+    use core::fmt::{Display, Formatter};
+    use core::marker::PhantomData;
+    use num_traits::Float;
+    use serde::de::{Error, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(z: &Complex<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Float + Display,
+        S: Serializer,
+    {
+        serializer.collect_str(z)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Complex<T>, D::Error>
+    where
+        T: Float,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ComplexVisitor(PhantomData))
+    }
+
+    struct ComplexVisitor<T>(PhantomData<T>);
+
+    impl<T: Float> Visitor<'_> for ComplexVisitor<T> {
+        type Value = Complex<T>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("a complex number like \"3 + 4i\"")
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Complex<T>, E> {
+            value.parse().map_err(E::custom)
+        }
+    }
+}
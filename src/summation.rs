@@ -0,0 +1,89 @@
+use crate::{Complex, ComplexPart};
+use core::iter::{Product, Sum};
+use num_traits::{Float, Num};
+
+/// Sum of Complex<T>
+impl<T: Num + Clone> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::new(T::zero(), T::zero()), |acc, z| acc + z)
+    }
+}
+
+/// Sum of &Complex<T>
+impl<'a, T: Num + Clone> Sum<&'a Complex<T>> for Complex<T> {
+    fn sum<I: Iterator<Item = &'a Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::new(T::zero(), T::zero()), |acc, z| acc + z)
+    }
+}
+
+/// Product of Complex<T>
+impl<T: ComplexPart> Product for Complex<T> {
+    fn product<I: Iterator<Item = Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::new(T::one(), T::zero()), |acc, z| acc * z)
+    }
+}
+
+/// Product of &Complex<T>
+impl<'a, T: ComplexPart> Product<&'a Complex<T>> for Complex<T> {
+    fn product<I: Iterator<Item = &'a Complex<T>>>(iter: I) -> Complex<T> {
+        iter.fold(Complex::new(T::one(), T::zero()), |acc, z| acc * z)
+    }
+}
+
+/// Running sum of one component that keeps track of the low-order bits lost to rounding.
+struct NeumaierSum<T> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: Float> NeumaierSum<T> {
+    fn new() -> Self {
+        Self {
+            sum: T::zero(),
+            compensation: T::zero(),
+        }
+    }
+
+    fn add(&mut self, value: T) {
+        let total = self.sum + value;
+        // Whichever operand is smaller is the one that lost bits in the addition
+        if T::abs(self.sum) >= T::abs(value) {
+            self.compensation = self.compensation + ((self.sum - total) + value);
+        } else {
+            self.compensation = self.compensation + ((value - total) + self.sum);
+        }
+        self.sum = total;
+    }
+
+    fn total(&self) -> T {
+        self.sum + self.compensation
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// Returns the sum of all numbers using Kahan–Neumaier compensated summation.
+    ///
+    /// This is slower than [`Iterator::sum`], but the rounding error doesn't grow with the
+    /// number of terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccmath::Complex;
+    ///
+    /// let big = Complex::new(1e100, -1e100);
+    /// let terms = [Complex::new(1.0, 1.0), big, Complex::new(1.0, 1.0), -big];
+    ///
+    /// assert_eq!(terms.iter().sum::<Complex<f64>>(), Complex::new(0.0, 0.0));
+    /// assert_eq!(Complex::compensated_sum(terms), Complex::new(2.0, 2.0));
+    /// ```
+    pub fn compensated_sum<I: IntoIterator<Item = Complex<T>>>(terms: I) -> Self {
+        let mut real = NeumaierSum::new();
+        let mut imag = NeumaierSum::new();
+        for z in terms {
+            real.add(z.real);
+            imag.add(z.imag);
+        }
+        Self::new(real.total(), imag.total())
+    }
+}
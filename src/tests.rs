@@ -23,22 +23,22 @@ fn unary_operators() {
     assert_eq!(z2.square_abs(), 27.85);
 
     // arg
-    assert_eq!(z1.arg(), 0.9272952180016123);
-    assert_eq!(z2.arg(), -0.1713791263895069);
+    assert!((z1.arg() - 0.9272952180016122).abs() <= f64::EPSILON);
+    assert!((z2.arg() + 0.17137912638950764).abs() <= f64::EPSILON);
 
     // inv
     assert_eq!(z1.inv(), Complex::new(0.12, -0.16));
-    assert_eq!(
+    assert_complex_relative_eq!(
         z2.inv(),
         Complex::new(0.1867145421903052, 0.03231597845601436)
     );
 
     // exp
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::new(0f64, f64::consts::PI).exp(),
         Complex::new(-1f64, 1.2246467991473532e-16)
     );
-    assert_eq!(
+    assert_complex_relative_eq!(
         z1.exp(),
         Complex::new(-13.128783081462158, -15.200784463067954)
     );
@@ -67,7 +67,7 @@ fn binary_operators() {
     // subtract
     assert_eq!(z1 - 5f32, Complex::new(-2f32, 4f32));
     assert_eq!(5f32 - z1, Complex::new(2f32, -4f32));
-    assert_eq!(z1 - z2, Complex::new(5.5, 10.23));
+    assert_eq!(z1 - z2, Complex::new(5.5, -2.23));
 
     // multiply
     assert_eq!(z1 * -0.5, Complex::new(-1.5, -2f32));
@@ -79,11 +79,11 @@ fn binary_operators() {
 
     // dividing
     assert_eq!(z1 / -0.5, Complex::new(-6f32, -8f32));
-    assert_eq!(1.8 / z2, Complex::new(-0.099860415, -0.24885215));
-    assert_eq!(
+    assert_complex_relative_eq!(1.8 / z2, Complex::new(-0.099860415, -0.24885215));
+    assert_complex_relative_eq!(
         z1 / z2,
         Complex::new(
-            0.38657075,
+            0.38657078,
             z1.real * z2.inv().imag + z1.imag * z2.inv().real
         )
     );
@@ -93,26 +93,26 @@ fn binary_operators() {
 
     // powi
     assert_eq!(z3.powi(6), Complex::new(11753f64, -10296f64));
-    assert_eq!(
+    assert_complex_relative_eq!(
         z4.powi(-2),
-        Complex::new(0.03381799780176568, 0.012067726245692974)
+        Complex::new(0.03381799780176567, 0.012067726245692972)
     );
 
     // powf
-    assert_eq!(z3.powf(3f64), Complex::new(-117f64, 43.99999999999998));
-    assert_eq!(
+    assert_complex_relative_eq!(z3.powf(3f64), Complex::new(-117f64, 44f64));
+    assert_complex_relative_eq!(
         z4.powf(-2.5),
-        Complex::new(0.014217542838549325, 0.00649377309897787)
+        Complex::new(0.014217542838549313, 0.006493773098977897)
     );
 
     // powc
-    assert_eq!(
+    assert_complex_relative_eq!(
         z3.powc(z4),
         Complex::new(-9667.467998399987, -2282.430226186542)
     );
-    assert_eq!(
+    assert_complex_relative_eq!(
         z4.powc(z3),
-        Complex::new(288.7067987011787, -41.7623644411436)
+        Complex::new(288.7067987011794, -41.762364441144356)
     );
 }
 
@@ -121,39 +121,39 @@ fn complex_trig() {
     let z1 = Complex::new(3f64, 4f64);
 
     // sin
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::sin(z1),
         Complex::new(3.853738037919377, -27.016813258003932)
     );
 
     // cos
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::cos(z1),
         Complex::new(-27.034945603074224, -3.851153334811777)
     );
 
     // tan
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::tan(z1),
-        Complex::new(-0.00018734620462948492, 0.999355987381473)
+        Complex::new(-0.0001873462046294785, 0.9993559873814731)
     );
 
     // cot
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::cot(z1),
-        Complex::new(-0.00018758773798366576, -1.0006443924715591)
+        Complex::new(-0.0001875877379836593, -1.0006443924715591)
     );
 
     // sec
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::sec(z1),
-        Complex::new(-0.03625349691586887, 0.005164344607753179)
+        Complex::new(-0.03625349691586887, 0.005164344607753178)
     );
 
     // csc
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::csc(z1),
-        Complex::new(0.005174473184019398, 0.03627588962862602)
+        Complex::new(0.0051744731840193976, 0.03627588962862602)
     );
 }
 
@@ -162,39 +162,39 @@ fn complex_inverse_trig() {
     let z1 = Complex::new(3f64, 4f64);
 
     // arcsin
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arcsin(z1),
-        Complex::new(0.6339838656391737, 2.305509031243471)
+        Complex::new(0.6339838656391766, 2.305509031243477)
     );
 
     // arccos
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arccos(z1),
-        Complex::new(0.9368124611557229, -2.305509031243471)
+        Complex::new(0.9368124611557199, -2.305509031243477)
     );
 
     // arctan
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arctan(z1),
-        Complex::new(1.4483069952314649, 0.1589971916799988)
+        Complex::new(1.4483069952314644, 0.15899719167999918)
     );
 
     // arccot
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arccot(z1),
-        Complex::new(0.12248933156343111, -0.1589971916799991)
+        Complex::new(0.12248933156343207, -0.1589971916799992)
     );
 
     // arcsec
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arcsec(z1),
-        Complex::new(1.4520455954874867, 0.1604455337745046)
+        Complex::new(1.452045595487485, 0.16044553377450496)
     );
 
     // arccsc
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arccsc(z1),
-        Complex::new(0.11875073130740989, -0.1604455337745046)
+        Complex::new(0.11875073130741173, -0.16044553377450496)
     );
 }
 
@@ -203,37 +203,37 @@ fn complex_hyperbolic_trig() {
     let z1 = Complex::new(3f64, 4f64);
 
     // sinh
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::sinh(z1),
         Complex::new(-6.5481200409110025, -7.61923172032141)
     );
 
     // cosh
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::cosh(z1),
         Complex::new(-6.580663040551157, -7.581552742746545)
     );
 
     // tanh
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::tanh(z1),
-        Complex::new(1.000709536067233, 0.004908258067495952)
+        Complex::new(1.0007095360672331, 0.00490825806749606)
     );
 
     // coth
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::coth(z1),
-        Complex::new(0.9992669278059015, -0.004901182394304366)
+        Complex::new(0.9992669278059014, -0.004901182394304472)
     );
 
     // sech
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::sech(z1),
-        Complex::new(-0.06529402785794704, 0.07522496030277322)
+        Complex::new(-0.06529402785794705, 0.07522496030277323)
     );
 
     // csch
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::csch(z1),
         Complex::new(-0.0648774713706355, 0.0754898329158637)
     );
@@ -244,38 +244,2081 @@ fn complex_inverse_hyperbolic_trig() {
     let z1 = Complex::new(3f64, 4f64);
 
     // arcsinh
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arcsinh(z1),
-        Complex::new(2.2999140408792695, 0.9176168533514786)
+        Complex::new(2.2999140408792695, 0.9176168533514785)
     );
 
     // arccosh
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arccosh(z1),
         Complex::new(2.305509031243477, 0.9368124611557199)
     );
 
     // arctanh
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arctanh(z1),
-        Complex::new(0.11750090731143398, 1.4099210495965755)
+        Complex::new(0.1175009073114339, 1.4099210495965755)
     );
 
     // arccoth
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arccoth(z1),
-        Complex::new(0.11750090731143398, -0.16087527719832115)
+        Complex::new(0.1175009073114339, -0.16087527719832106)
     );
 
     // arcsech
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arcsech(z1),
-        Complex::new(0.1604455337745046, -1.4520455954874867)
+        Complex::new(0.16044553377450496, -1.452045595487485)
     );
 
     // arccsch
-    assert_eq!(
+    assert_complex_relative_eq!(
         Complex::arccsch(z1),
-        Complex::new(0.12124561370968728, -0.15950663187736328)
+        Complex::new(0.12124561370968746, -0.15950663187736358)
+    );
+}
+
+#[test]
+fn parsing() {
+    // forms produced by display
+    assert_eq!("3 + 4i".parse(), Ok(Complex::new(3f64, 4f64)));
+    assert_eq!("3 - 4i".parse(), Ok(Complex::new(3f64, -4f64)));
+
+    // single terms
+    assert_eq!("4i".parse(), Ok(Complex::new(0f64, 4f64)));
+    assert_eq!("-i".parse(), Ok(Complex::new(0f64, -1f64)));
+    assert_eq!("3".parse(), Ok(Complex::new(3f64, 0f64)));
+
+    // engineering notation, scientific notation and special values
+    assert_eq!("3+4j".parse(), Ok(Complex::new(3f64, 4f64)));
+    assert_eq!("1.5e3 - 2E-2i".parse(), Ok(Complex::new(1500f64, -0.02)));
+    assert_eq!("1e+2+i".parse(), Ok(Complex::new(100f64, 1f64)));
+    assert_eq!(
+        "-inf + infi".parse(),
+        Ok(Complex::new(f64::NEG_INFINITY, f64::INFINITY))
+    );
+    let z: Complex<f32> = "nan - 2i".parse().unwrap();
+    assert!(z.real().is_nan());
+    assert_eq!(z.imag(), -2f32);
+
+    // errors
+    let error = "  ".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::Empty);
+
+    let error = "3 + 4x".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::InvalidNumber);
+    assert_eq!(error.position(), 4);
+
+    let error = "3 4i".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::MissingOperator);
+    assert_eq!(error.position(), 2);
+
+    let error = "3 +".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::MissingTerm);
+    assert_eq!(error.position(), 2);
+
+    // the operator is the sign of the term, so the old display output 3 - -4i doesn't flip it
+    for (input, position) in [("3 - -4i", 2), ("3++4i", 1), ("++3", 0)] {
+        let error = input.parse::<Complex<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseComplexErrorKind::MissingTerm, "{input}");
+        assert_eq!(error.position(), position, "{input}");
+    }
+
+    // stray characters after a complete term
+    let error = "3 + 4 i".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::MissingOperator);
+    assert_eq!(error.position(), 6);
+    let error = "3 + 4ï".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::InvalidNumber);
+    assert_eq!(error.position(), 5);
+    let error = "3i,".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::InvalidNumber);
+    assert_eq!(error.position(), 2);
+
+    let error = "3i + 4i".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(error.kind(), ParseComplexErrorKind::DuplicatePart);
+    assert_eq!(error.position(), 3);
+    assert_eq!(
+        error.to_string(),
+        "real or imaginary part given twice at position 3"
+    );
+}
+
+#[test]
+fn polar() {
+    let z = Complex::new(3f64, 4f64);
+    let p = Polar::from(z);
+
+    // conversions
+    assert_eq!(p.modulus(), 5f64);
+    assert!((p.angle() - 0.9272952180016122).abs() <= f64::EPSILON);
+    assert_complex_relative_eq!(
+        Complex::from(p),
+        Complex::new(3.0000000000000004, 3.9999999999999996)
+    );
+    assert_eq!(Complex::from_polar(2f64, 0f64), Complex::new(2f64, 0f64));
+    assert_eq!(
+        Polar::from(Complex::new(-1f64, -0f64)).angle(),
+        f64::consts::PI
+    );
+    assert_complex_relative_eq!(
+        Complex::cis(f64::consts::FRAC_PI_2),
+        Complex::new(6.123233995736766e-17, 1f64)
+    );
+
+    // normalisation
+    assert_eq!(
+        Polar::new(1f64, 3f64 * f64::consts::PI).normalize(),
+        Polar::new(1f64, f64::consts::PI)
+    );
+    assert_eq!(
+        Polar::new(1f64, -f64::consts::PI).normalize(),
+        Polar::new(1f64, f64::consts::PI)
+    );
+    assert_eq!(
+        Polar::new(-2f64, 0f64).normalize(),
+        Polar::new(2f64, f64::consts::PI)
+    );
+
+    // multiplication and division
+    let p1 = Polar::new(2f64, 3f64);
+    let p2 = Polar::new(4f64, 1f64);
+    assert_eq!(p1 * p2, Polar::new(8f64, 4f64 - 2f64 * f64::consts::PI));
+    assert_eq!(p2 / p1, Polar::new(2f64, -2f64));
+
+    // powers
+    assert_eq!(p2.powi(3), Polar::new(64f64, 3f64));
+    assert_eq!(p2.powf(0.5), Polar::new(2f64, 0.5));
+    assert_eq!(p2.powi(-1), p2.inv());
+}
+
+#[test]
+fn num_traits() {
+    use num_traits::{
+        ConstOne, ConstZero, FromPrimitive, Inv, MulAdd, Num, NumCast, One, Pow, ToPrimitive, Zero,
+    };
+
+    let z1 = Complex::new(3f64, 4f64);
+    let z2 = Complex::new(5.2, -0.9);
+
+    // identities
+    assert_eq!(Complex::<f64>::zero(), Complex::new(0f64, 0f64));
+    assert_eq!(Complex::<f64>::one(), Complex::new(1f64, 0f64));
+    assert_eq!(<Complex<f64> as ConstZero>::ZERO, Complex::zero());
+    assert_eq!(<Complex<f64> as ConstOne>::ONE, Complex::one());
+    assert!(Complex::new(0f64, -0f64).is_zero());
+    assert!(!Complex::new(1f64, 1f64).is_one());
+
+    // num
+    assert_eq!(
+        Complex::<f64>::from_str_radix("ff - 10i", 16),
+        Ok(Complex::new(255f64, -16f64))
+    );
+    assert_eq!(
+        Complex::<f64>::from_str_radix("101+1.1i", 2),
+        Ok(Complex::new(5f64, 1.5))
+    );
+    assert_eq!(
+        Complex::<f64>::from_str_radix("1", 19).unwrap_err().kind(),
+        ParseComplexErrorKind::InvalidRadix
+    );
+    assert_eq!(
+        Complex::<f64>::from_str_radix("1e2 + 1e-2i", 10),
+        Ok(Complex::new(100f64, 0.01))
+    );
+    for (input, radix) in [("1e10", 2), ("1 - 1e-1i", 8), ("1p4", 16), ("1P-1i", 16)] {
+        assert_eq!(
+            Complex::<f64>::from_str_radix(input, radix)
+                .unwrap_err()
+                .kind(),
+            ParseComplexErrorKind::InvalidNumber,
+            "{input} in radix {radix}"
+        );
+    }
+    assert_eq!(
+        Complex::<f64>::from_str_radix("1e", 16),
+        Ok(Complex::new(30f64, 0f64))
+    );
+    assert_eq!(z1 % Complex::new(2f64, 0f64), Complex::new(1f64, 0f64));
+    assert_eq!(z1 % z1, Complex::zero());
+
+    // operators
+    assert_eq!(Inv::inv(z1), z1.inv());
+    assert_eq!(z1.pow(6i64), z1.powi(6));
+    assert_eq!(z2.pow(-2.5), z2.powf(-2.5));
+    assert_eq!(z1.pow(z2), z1.powc(z2));
+    assert_eq!(
+        z1.mul_add(Complex::new(2f64, 1f64), Complex::new(1f64, 1f64)),
+        Complex::new(3f64, 12f64)
+    );
+
+    // conversions
+    assert_eq!(
+        Complex::<f64>::from_i64(-3),
+        Some(Complex::new(-3f64, 0f64))
+    );
+    assert_eq!(
+        <Complex<f32> as NumCast>::from(2u8),
+        Some(Complex::new(2f32, 0f32))
+    );
+    assert_eq!(Complex::new(7.9, 0f64).to_i64(), Some(7));
+    assert_eq!(z1.to_f64(), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct State {
+        default: Complex<f64>,
+        #[serde(with = "crate::serialization::array")]
+        array: Complex<f64>,
+        #[serde(with = "crate::serialization::string")]
+        string: Complex<f64>,
+    }
+
+    let state = State {
+        default: Complex::new(3f64, 4f64),
+        array: Complex::new(-1.5, 0f64),
+        string: Complex::new(2f64, 0.25),
+    };
+    let json = r#"{"default":{"re":3.0,"im":4.0},"array":[-1.5,0.0],"string":"2 + 0.25i"}"#;
+
+    assert_eq!(serde_json::to_string(&state).unwrap(), json);
+    assert_eq!(serde_json::from_str::<State>(json).unwrap(), state);
+    assert!(serde_json::from_str::<State>(&json.replace("0.25i", "0.25x")).is_err());
+
+    // string round trip with a negative imaginary part
+    let z = Complex::new(3.0, -4.0);
+    let mut json = Vec::new();
+    crate::serialization::string::serialize(&z, &mut serde_json::Serializer::new(&mut json))
+        .unwrap();
+    assert_eq!(json, br#""3 - 4i""#);
+    let mut deserializer = serde_json::Deserializer::from_slice(&json);
+    assert_eq!(
+        crate::serialization::string::deserialize::<f64, _>(&mut deserializer).unwrap(),
+        z
+    );
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn reference_operators() {
+    fn dot<T>(a: &[T], b: &[T], zero: T) -> T
+    where
+        for<'a> &'a T: std::ops::Mul<&'a T, Output = T>,
+        T: std::ops::Add<T, Output = T>,
+    {
+        a.iter().zip(b).fold(zero, |acc, (x, y)| acc + x * y)
+    }
+
+    let z1 = Complex::new(3f64, 4f64);
+    let z2 = Complex::new(5.2, -0.9);
+
+    // borrowed and mixed operands
+    assert_eq!(&z1 + &z2, z1 + z2);
+    assert_eq!(&z1 - z2, z1 - z2);
+    assert_eq!(z1 * &z2, z1 * z2);
+    assert_eq!(&z1 / &z2, z1 / z2);
+    assert_eq!(&z1 % &z2, z1 % z2);
+    assert_eq!(&z1 % z2, z1 % &z2);
+    assert_eq!(z1 % 2f64, Complex::new(1f64, 0f64));
+    assert_eq!(&z1 % &2f64, z1 % 2f64);
+    assert_eq!(&z1 * 2f64, z1 * 2f64);
+    assert_eq!(z1 - &2f64, z1 - 2f64);
+    assert_eq!(&z1 / &2f64, z1 / 2f64);
+    assert_eq!(-&z1, -z1);
+
+    // assignment
+    let mut z3 = z1;
+    z3 += &z2;
+    z3 -= &1f64;
+    z3 *= &z2;
+    z3 /= &2f64;
+    assert_eq!(z3, (z1 + z2 - 1f64) * z2 / 2f64);
+    let mut z4 = z1;
+    z4 %= &z2;
+    z4 %= 2f64;
+    assert_eq!(z4, z1 % z2 % 2f64);
+
+    // generic code
+    assert_eq!(
+        dot(&[z1, z2], &[z2, z1], Complex::new(0f64, 0f64)),
+        z1 * z2 + z2 * z1
+    );
+}
+
+#[test]
+fn sum_and_product() {
+    let numbers = vec![
+        Complex::new(3f64, 4f64),
+        Complex::new(5.2, -0.9),
+        Complex::new(-1f64, 2f64),
+    ];
+
+    // sum
+    assert_eq!(
+        numbers.iter().sum::<Complex<f64>>(),
+        numbers[0] + numbers[1] + numbers[2]
+    );
+    assert_eq!(
+        numbers.clone().into_iter().sum::<Complex<f64>>(),
+        numbers[0] + numbers[1] + numbers[2]
+    );
+    assert_eq!(
+        Vec::<Complex<f64>>::new().into_iter().sum::<Complex<f64>>(),
+        Complex::new(0f64, 0f64)
+    );
+
+    // product
+    assert_eq!(
+        numbers.iter().product::<Complex<f64>>(),
+        numbers[0] * numbers[1] * numbers[2]
+    );
+    assert_eq!(
+        numbers.clone().into_iter().product::<Complex<f64>>(),
+        numbers[0] * numbers[1] * numbers[2]
+    );
+
+    // compensated sum
+    let tiny = Complex::new(1e-17, -1e-17);
+    let terms = std::iter::once(Complex::new(1f64, 1f64)).chain(std::iter::repeat_n(tiny, 10000));
+    assert_eq!(
+        terms.clone().sum::<Complex<f64>>(),
+        Complex::new(1f64, 1f64)
+    );
+    assert_complex_relative_eq!(
+        Complex::compensated_sum(terms),
+        Complex::new(1.0000000000001, 0.9999999999999)
+    );
+}
+
+#[test]
+fn mixed_types() {
+    let z = Complex::new(3f64, 4f64);
+
+    // conversions
+    assert_eq!(Complex::from(2.5), Complex::new(2.5, 0f64));
+    assert_eq!(Complex::from((1f32, -2f32)), Complex::new(1f32, -2f32));
+    assert_eq!(Complex::from([1f64, -2f64]), Complex::new(1f64, -2f64));
+    assert_eq!(
+        Complex::<f64>::from(Complex::new(0.1f32, -1e-30f32)),
+        Complex::new(0.1f32 as f64, -1e-30f32 as f64)
+    );
+    let w: Complex<f64> = 7f64.into();
+    assert_eq!(w, Complex::new(7f64, 0f64));
+
+    // integer scalars
+    assert_eq!(2 * z, Complex::new(6f64, 8f64));
+    assert_eq!(z + 1, Complex::new(4f64, 4f64));
+    assert_eq!(1 - z, Complex::new(-2f64, -4f64));
+    assert_eq!(z / 2i64, Complex::new(1.5, 2f64));
+    assert_eq!(25u32 / z, Complex::new(3f64, -4f64));
+    assert_eq!(Complex::new(1f32, 1f32) * 3u32, Complex::new(3f32, 3f32));
+
+    let mut z2 = z;
+    z2 += 1;
+    z2 -= 2i64;
+    z2 *= 2u32;
+    z2 /= 4;
+    assert_eq!(z2, Complex::new(1f64, 2f64));
+}
+
+#[test]
+fn formatting() {
+    let z1 = Complex::new(3f64, 4f64);
+    let z2 = Complex::new(3f64, -4f64);
+    let z3 = Complex::new(-1.23456, -0f64);
+
+    // signs
+    assert_eq!(z1.to_string(), "3 + 4i");
+    assert_eq!(z2.to_string(), "3 - 4i");
+    assert_eq!(z3.to_string(), "-1.23456 - 0i");
+    assert_eq!(format!("{:+}", z1), "+3 + 4i");
+
+    // precision and width
+    assert_eq!(format!("{:.2}", z3), "-1.23 - 0.00i");
+    assert_eq!(format!("{:10}", z1), "    3 + 4i");
+    assert_eq!(format!("{:<10}|", z1), "3 + 4i    |");
+    assert_eq!(format!("{:*^10.1}", z2), "3.0 - 4.0i");
+    assert_eq!(format!("{:*^12}", z2), "***3 - 4i***");
+    assert_eq!(
+        format!("{:014.1}", Complex::new(-1.5, 2.0)),
+        "-0001.5 + 2.0i"
+    );
+    assert_eq!(format!("{:+012}", z2), "+000003 - 4i");
+    assert_eq!(format!("{:<010}", z1), "00003 + 4i");
+    assert_eq!(format!("{:09}", Complex::new(f64::NAN, 1.0)), "0NaN + 1i");
+
+    // scientific notation
+    assert_eq!(
+        format!("{:e}", Complex::new(1500f64, -0.25)),
+        "1.5e3 - 2.5e-1i"
+    );
+    assert_eq!(
+        format!("{:.1E}", Complex::new(1500f64, 0.25)),
+        "1.5E3 + 2.5E-1i"
+    );
+
+    // engineering notation
+    assert_eq!(format!("{:#}", z2), "3 - 4j");
+
+    // display output can be parsed back
+    for z in [z1, z2, z3, Complex::new(f64::INFINITY, -1e-300)] {
+        assert_eq!(z.to_string().parse(), Ok(z));
+        assert_eq!(format!("{:#e}", z).parse(), Ok(z));
+    }
+}
+
+#[test]
+fn markup_formatting() {
+    let z1 = Complex::new(3f64, 4f64);
+    let z2 = Complex::new(-1.5, -2f64);
+
+    // polar
+    assert_eq!(format!("{:.3}", z1.display_polar()), "5.000∠0.927");
+    assert_eq!(
+        z2.display_polar().degrees().precision(1).to_string(),
+        "2.5∠-126.9°"
+    );
+    assert_eq!(
+        format!("{:.2}", z1.display_polar().exponential()),
+        "5.00·e^{i0.93}"
+    );
+
+    // latex
+    assert_eq!(z1.to_latex(), "3 + 4i");
+    assert_eq!(z2.to_latex(), "-1.5 - 2i");
+    assert_eq!(
+        Complex::new(f64::NAN, f64::NEG_INFINITY).to_latex(),
+        r"\mathrm{NaN} - \infty i"
+    );
+    assert_eq!(
+        z2.display_latex().polar().precision(2).to_string(),
+        "2.50 e^{-2.21i}"
+    );
+
+    // typst
+    assert_eq!(z1.to_typst(), "3 + 4i");
+    assert_eq!(format!("{:.1}", z2.display_typst()), "-1.5 - 2.0i");
+    assert_eq!(
+        Complex::new(f64::INFINITY, f64::NAN).to_typst(),
+        "infinity + \"NaN\"i"
+    );
+    assert_eq!(
+        z1.display_typst().polar().precision(3).to_string(),
+        "5.000 e^(0.927i)"
+    );
+}
+
+#[test]
+fn extreme_magnitudes() {
+    let p = |exponent: i32| f64::powf(2f64, f64::from(exponent));
+
+    // abs
+    assert_eq!(Complex::new(1e200, 1e200).abs(), 1.414213562373095e+200);
+    assert_eq!(Complex::new(1e-200, 3e-200).abs(), 3.1622776601683794e-200);
+    assert_eq!(Complex::new(f32::MAX, f32::MAX).abs(), f32::INFINITY);
+    let big = f32::powi(2f32, 125);
+    assert_eq!(Complex::new(3f32 * big, 4f32 * big).abs(), 5f32 * big);
+    assert_eq!(Complex::new(3e37f32, 4e37f32).abs(), 5e37f32);
+
+    // inv
+    assert_eq!(
+        Complex::new(1e300, 1e300).inv(),
+        Complex::new(5e-301, -5e-301)
+    );
+    assert_eq!(
+        Complex::new(p(-1023), p(-1023)).inv(),
+        Complex::new(p(1022), -p(1022))
+    );
+
+    // division
+    assert_eq!(
+        Complex::new(1f64, 1f64) / Complex::new(1f64, p(1023)),
+        Complex::new(p(-1023), -p(-1023))
+    );
+    assert_eq!(
+        Complex::new(1f64, 1f64) / Complex::new(p(-1023), p(-1023)),
+        Complex::new(p(1023), 0f64)
+    );
+    assert_eq!(
+        Complex::new(p(1023), p(-1023)) / Complex::new(p(677), p(-677)),
+        Complex::new(p(346), -p(-1008))
+    );
+    assert_eq!(
+        Complex::new(p(1023), p(1023)) / Complex::new(1f64, 1f64),
+        Complex::new(p(1023), 0f64)
+    );
+    assert_complex_relative_eq!(
+        Complex::new(p(-347), p(-54)) / Complex::new(p(-1037), p(-1058)),
+        Complex::new(3.8981256045591133e+289, 8.174961907852354e+295)
+    );
+    let quotient = Complex::new(p(-1074), p(-1074)) / Complex::new(p(-1073), p(-1074));
+    assert!((quotient - Complex::new(0.6, 0.2)).abs() <= f64::EPSILON);
+    assert_eq!(
+        Complex::new(p(1015), p(-989)) / Complex::new(p(1023), p(1023)),
+        Complex::new(p(-9), -p(-9))
+    );
+    assert_complex_relative_eq!(
+        Complex::new(p(-622), p(-1071)) / Complex::new(p(-343), p(-798)),
+        Complex::new(1.0295115178936058e-84, 6.971459875150762e-220)
+    );
+    assert_eq!(
+        1f32 / Complex::new(f32::powi(2f32, 120), f32::powi(2f32, 120)),
+        Complex::new(f32::powi(2f32, -121), -f32::powi(2f32, -121))
+    );
+}
+
+/// The standard leaves the sign of the real part unspecified
+const ANY_REAL_SIGN: u8 = 1;
+/// The standard leaves the sign of the imaginary part unspecified
+const ANY_IMAG_SIGN: u8 = 2;
+
+/// Symmetries from C99 Annex G, used to check the mirrored entries of each table
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    /// f(conj(z)) = conj(f(z))
+    Conjugate,
+    /// Also f(-z) = -f(z)
+    Odd,
+    /// Also f(-z) = f(z)
+    Even,
+}
+
+/// A row of a special value table: input real, input imaginary, expected real, expected imaginary,
+/// and which signs are unspecified
+type SpecialValue = (f64, f64, f64, f64, u8);
+
+/// NaNs match any NaN, zeros and infinities also have to match in sign when it is specified.
+fn matches_special(value: f64, expected: f64, signed: bool) -> bool {
+    if expected.is_nan() {
+        value.is_nan()
+    } else if signed {
+        value == expected && value.is_sign_negative() == expected.is_sign_negative()
+    } else {
+        value.abs() == expected.abs()
+    }
+}
+
+fn check_special_values(
+    name: &str,
+    function: fn(Complex<f64>) -> Complex<f64>,
+    symmetry: Symmetry,
+    table: &[SpecialValue],
+) {
+    for &(x, y, real, imag, unspecified) in table {
+        let mut cases = vec![((x, y), (real, imag)), ((x, -y), (real, -imag))];
+        match symmetry {
+            Symmetry::Conjugate => {}
+            Symmetry::Odd => {
+                cases.push(((-x, -y), (-real, -imag)));
+                cases.push(((-x, y), (-real, imag)));
+            }
+            Symmetry::Even => {
+                cases.push(((-x, -y), (real, imag)));
+                cases.push(((-x, y), (real, -imag)));
+            }
+        }
+
+        for ((x, y), (real, imag)) in cases {
+            let result = function(Complex::new(x, y));
+            assert!(
+                matches_special(result.real(), real, unspecified & ANY_REAL_SIGN == 0)
+                    && matches_special(result.imag(), imag, unspecified & ANY_IMAG_SIGN == 0),
+                "{}({:?}) returned {:?}, expected {:?}",
+                name,
+                (x, y),
+                (result.real(), result.imag()),
+                (real, imag)
+            );
+        }
+    }
+}
+
+#[test]
+fn annex_g_special_values() {
+    use f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    const INF: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+    let three_pi_4 = 3f64 * FRAC_PI_4;
+
+    // G.6.1.1
+    let arccos: Vec<SpecialValue> = vec![
+        (0f64, 0f64, FRAC_PI_2, -0f64, 0),
+        (-0f64, 0f64, FRAC_PI_2, -0f64, 0),
+        (0f64, NAN, FRAC_PI_2, NAN, 0),
+        (-0f64, NAN, FRAC_PI_2, NAN, 0),
+        (2f64, INF, FRAC_PI_2, -INF, 0),
+        (-2f64, INF, FRAC_PI_2, -INF, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (-INF, 3f64, PI, -INF, 0),
+        (INF, 3f64, 0f64, -INF, 0),
+        (-INF, INF, three_pi_4, -INF, 0),
+        (INF, INF, FRAC_PI_4, -INF, 0),
+        (INF, NAN, NAN, INF, ANY_IMAG_SIGN),
+        (-INF, NAN, NAN, INF, ANY_IMAG_SIGN),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, INF, NAN, -INF, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("arccos", Complex::arccos, Symmetry::Conjugate, &arccos);
+
+    // G.6.2.1
+    let arccosh: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 0f64, FRAC_PI_2, 0),
+        (-0f64, 0f64, 0f64, FRAC_PI_2, 0),
+        (2f64, INF, INF, FRAC_PI_2, 0),
+        (-2f64, INF, INF, FRAC_PI_2, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (-INF, 3f64, INF, PI, 0),
+        (INF, 3f64, INF, 0f64, 0),
+        (-INF, INF, INF, three_pi_4, 0),
+        (INF, INF, INF, FRAC_PI_4, 0),
+        (INF, NAN, INF, NAN, 0),
+        (-INF, NAN, INF, NAN, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, INF, INF, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("arccosh", Complex::arccosh, Symmetry::Conjugate, &arccosh);
+
+    // G.6.2.2
+    let arcsinh: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 0f64, 0f64, 0),
+        (2f64, INF, INF, FRAC_PI_2, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (INF, 3f64, INF, 0f64, 0),
+        (INF, INF, INF, FRAC_PI_4, 0),
+        (INF, NAN, INF, NAN, 0),
+        (NAN, 0f64, NAN, 0f64, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, INF, INF, NAN, ANY_REAL_SIGN),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("arcsinh", Complex::arcsinh, Symmetry::Odd, &arcsinh);
+
+    // G.6.2.3
+    let arctanh: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 0f64, 0f64, 0),
+        (0f64, NAN, 0f64, NAN, 0),
+        (1f64, 0f64, INF, 0f64, 0),
+        (2f64, INF, 0f64, FRAC_PI_2, 0),
+        (0f64, INF, 0f64, FRAC_PI_2, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (INF, 3f64, 0f64, FRAC_PI_2, 0),
+        (INF, INF, 0f64, FRAC_PI_2, 0),
+        (INF, NAN, 0f64, NAN, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, INF, 0f64, FRAC_PI_2, ANY_REAL_SIGN),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("arctanh", Complex::arctanh, Symmetry::Odd, &arctanh);
+
+    // G.6.2.4
+    let cosh: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 1f64, 0f64, 0),
+        (0f64, INF, NAN, 0f64, ANY_IMAG_SIGN),
+        (0f64, NAN, NAN, 0f64, ANY_IMAG_SIGN),
+        (2f64, INF, NAN, NAN, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (INF, 0f64, INF, 0f64, 0),
+        (INF, 3f64, -INF, INF, 0),
+        (INF, INF, INF, NAN, ANY_REAL_SIGN),
+        (INF, NAN, INF, NAN, 0),
+        (NAN, 0f64, NAN, 0f64, ANY_IMAG_SIGN),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("cosh", Complex::cosh, Symmetry::Even, &cosh);
+
+    // G.6.2.5
+    let sinh: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 0f64, 0f64, 0),
+        (0f64, INF, 0f64, NAN, ANY_REAL_SIGN),
+        (0f64, NAN, 0f64, NAN, ANY_REAL_SIGN),
+        (2f64, INF, NAN, NAN, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (INF, 0f64, INF, 0f64, 0),
+        (INF, 3f64, -INF, INF, 0),
+        (INF, INF, INF, NAN, ANY_REAL_SIGN),
+        (INF, NAN, INF, NAN, ANY_REAL_SIGN),
+        (NAN, 0f64, NAN, 0f64, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("sinh", Complex::sinh, Symmetry::Odd, &sinh);
+
+    // G.6.2.6
+    let tanh: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 0f64, 0f64, 0),
+        (0f64, INF, 0f64, NAN, 0),
+        (0f64, NAN, 0f64, NAN, 0),
+        (2f64, INF, NAN, NAN, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (INF, 3f64, 1f64, -0f64, 0),
+        (INF, INF, 1f64, 0f64, ANY_IMAG_SIGN),
+        (INF, NAN, 1f64, 0f64, ANY_IMAG_SIGN),
+        (NAN, 0f64, NAN, 0f64, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("tanh", Complex::tanh, Symmetry::Odd, &tanh);
+
+    // G.6.3.1
+    let exp: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 1f64, 0f64, 0),
+        (-0f64, 0f64, 1f64, 0f64, 0),
+        (2f64, INF, NAN, NAN, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (INF, 0f64, INF, 0f64, 0),
+        (-INF, 3f64, -0f64, 0f64, 0),
+        (INF, 3f64, -INF, INF, 0),
+        (-INF, INF, 0f64, 0f64, ANY_REAL_SIGN | ANY_IMAG_SIGN),
+        (INF, INF, INF, NAN, ANY_REAL_SIGN),
+        (-INF, NAN, 0f64, 0f64, ANY_REAL_SIGN | ANY_IMAG_SIGN),
+        (INF, NAN, INF, NAN, ANY_REAL_SIGN),
+        (NAN, 0f64, NAN, 0f64, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("exp", Complex::exp, Symmetry::Conjugate, &exp);
+
+    // G.6.3.2
+    let ln: Vec<SpecialValue> = vec![
+        (-0f64, 0f64, -INF, PI, 0),
+        (0f64, 0f64, -INF, 0f64, 0),
+        (2f64, INF, INF, FRAC_PI_2, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (-INF, 3f64, INF, PI, 0),
+        (INF, 3f64, INF, 0f64, 0),
+        (-INF, INF, INF, three_pi_4, 0),
+        (INF, INF, INF, FRAC_PI_4, 0),
+        (INF, NAN, INF, NAN, 0),
+        (-INF, NAN, INF, NAN, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, INF, INF, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("ln", Complex::ln, Symmetry::Conjugate, &ln);
+
+    // G.6.4.2
+    let sqrt: Vec<SpecialValue> = vec![
+        (0f64, 0f64, 0f64, 0f64, 0),
+        (-0f64, 0f64, 0f64, 0f64, 0),
+        (2f64, INF, INF, INF, 0),
+        (-INF, INF, INF, INF, 0),
+        (NAN, INF, INF, INF, 0),
+        (2f64, NAN, NAN, NAN, 0),
+        (-INF, 3f64, 0f64, INF, 0),
+        (INF, 3f64, INF, 0f64, 0),
+        (-INF, NAN, NAN, INF, ANY_IMAG_SIGN),
+        (INF, NAN, INF, NAN, 0),
+        (NAN, 3f64, NAN, NAN, 0),
+        (NAN, NAN, NAN, NAN, 0),
+    ];
+    check_special_values("sqrt", Complex::sqrt, Symmetry::Conjugate, &sqrt);
+
+    // The trigonometric functions are defined through the hyperbolic ones, like sin(z) = -i sinh(iz)
+    let values = [0f64, -0f64, 2f64, -2f64, INF, -INF, NAN];
+    let mul_i = |z: Complex<f64>| Complex::new(-z.imag(), z.real());
+    let mul_neg_i = |z: Complex<f64>| Complex::new(z.imag(), -z.real());
+    let same = |a: Complex<f64>, b: Complex<f64>| {
+        matches_special(a.real(), b.real(), true) && matches_special(a.imag(), b.imag(), true)
+    };
+    for x in values {
+        for y in values {
+            let z = Complex::new(x, y);
+            assert!(same(z.sin(), mul_neg_i(mul_i(z).sinh())));
+            assert!(same(z.cos(), mul_i(z).cosh()));
+            assert!(same(z.tan(), mul_neg_i(mul_i(z).tanh())));
+            assert!(same(z.arcsin(), mul_neg_i(mul_i(z).arcsinh())));
+            assert!(same(z.arctan(), mul_neg_i(mul_i(z).arctanh())));
+        }
+    }
+
+    // arg respects signed zeros
+    assert_eq!(Complex::new(-0f64, 0f64).arg(), PI);
+    assert_eq!(Complex::new(-0f64, -0f64).arg(), -PI);
+    assert_eq!(Complex::new(-1f64, -0f64).arg(), -PI);
+    assert!(Complex::new(0f64, -0f64).arg().is_sign_negative());
+
+    // the sign of a zero imaginary part picks the side of the branch cut of sqrt
+    assert_eq!(Complex::new(-4f64, 0f64).sqrt(), Complex::new(0f64, 2f64));
+    assert_eq!(Complex::new(-4f64, -0f64).sqrt(), Complex::new(0f64, -2f64));
+
+    // operators produce infinities instead of NaNs
+    let is_infinite = |z: Complex<f64>| z.real().is_infinite() || z.imag().is_infinite();
+    assert!(is_infinite(
+        Complex::new(INF, 0f64) * Complex::new(0f64, 1f64)
+    ));
+    assert!(is_infinite(
+        Complex::new(INF, NAN) * Complex::new(2f64, 3f64)
+    ));
+    assert!(is_infinite(
+        Complex::new(1f64, 1f64) / Complex::new(0f64, 0f64)
+    ));
+    assert!(is_infinite(
+        Complex::new(INF, INF) / Complex::new(2f64, 3f64)
+    ));
+    assert!(is_infinite(Complex::new(0f64, 0f64).inv()));
+    assert_eq!(
+        (Complex::new(1f64, 1f64) / Complex::new(INF, NAN)).abs(),
+        0f64
+    );
+}
+
+/// An input and the correctly rounded result
+type ReferenceValue = ((f64, f64), (f64, f64));
+
+/// Checks a function against reference values, allowing an error of a few ulps
+fn check_reference_values(
+    name: &str,
+    function: fn(Complex<f64>) -> Complex<f64>,
+    table: &[ReferenceValue],
+) {
+    for &((x, y), (real, imag)) in table {
+        let result = function(Complex::new(x, y));
+        assert!(
+            result.ulps_eq(Complex::new(real, imag), 4),
+            "{}({:?}) returned {:?}, expected {:?}",
+            name,
+            (x, y),
+            (result.real(), result.imag()),
+            (real, imag)
+        );
+    }
+}
+
+#[test]
+fn inverse_functions_accuracy() {
+    use f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, PI};
+
+    // Reference values computed with 5000 bit precision, near the branch points, the real axis and
+    // the ends of the exponent range
+    let arcsin = [
+        (
+            (1.0000000009313226, 1e-12),
+            (1.570796303624425, 4.315837909158074e-05),
+        ),
+        ((1.0, 1e-300), (FRAC_PI_2, 1e-150)),
+        ((0.5, 1e-20), (FRAC_PI_6, 1.1547005383792515e-20)),
+        ((1e+300, 1e+300), (FRAC_PI_4, 691.8152486690536)),
+        ((1e-300, 1e-300), (1e-300, 1e-300)),
+        ((1e-08, 100000000.0), (1e-16, 19.11382792451231)),
+        ((2.0, 1e-17), (FRAC_PI_2, 1.3169578969248168)),
+        (
+            (0.9999999, 1e-07),
+            (1.570304980319887, 0.00020352237864605657),
+        ),
+        ((1e-05, 1.0), (7.071067811836013e-06, 0.8813735870372207)),
+        (
+            (-10000000000.0, 3.0),
+            (-1.5707963264948965, 23.7189981105004),
+        ),
+        ((1e-200, 1.0), (7.071067811865475e-201, 0.881373587019543)),
+        ((0.3, -0.4), (0.2806295622918058, -0.4051123371780309)),
+        ((-0.999, 0.001), (-1.5216592817544534, 0.020358029698343834)),
+        ((1e+200, 1e-200), (FRAC_PI_2, 461.2101657793691)),
+        ((0.0, 0.5), (0.0, 0.48121182505960347)),
+        ((1.0, 1.0), (0.6662394324925153, 1.0612750619050357)),
+        ((1.0, 0.0), (FRAC_PI_2, 0.0)),
+        ((1.0, -0.0), (FRAC_PI_2, -0.0)),
+        ((-1.0, 0.0), (-FRAC_PI_2, 0.0)),
+        ((-1.0, -0.0), (-FRAC_PI_2, -0.0)),
+    ];
+    let arccos = [
+        (
+            (1.0000000009313226, 1e-12),
+            (2.3170471661310312e-08, -4.315837909158074e-05),
+        ),
+        ((1.0, 1e-300), (1e-150, -1e-150)),
+        ((0.5, 1e-20), (FRAC_PI_3, -1.1547005383792515e-20)),
+        ((1e+300, 1e+300), (FRAC_PI_4, -691.8152486690536)),
+        ((1e-300, 1e-300), (FRAC_PI_2, -1e-300)),
+        ((1e-08, 100000000.0), (FRAC_PI_2, -19.11382792451231)),
+        ((2.0, 1e-17), (5.773502691896258e-18, -1.3169578969248168)),
+        (
+            (0.9999999, 1e-07),
+            (0.0004913464750097201, -0.00020352237864605657),
+        ),
+        ((1e-05, 1.0), (1.5707892557270848, -0.8813735870372207)),
+        (
+            (-10000000000.0, 3.0),
+            (3.141592653289793, -23.7189981105004),
+        ),
+        ((1e-200, 1.0), (FRAC_PI_2, -0.881373587019543)),
+        ((0.3, -0.4), (1.2901667645030908, 0.4051123371780309)),
+        ((-0.999, 0.001), (3.09245560854935, -0.020358029698343834)),
+        ((1e+200, 1e-200), (0.0, -461.2101657793691)),
+        ((0.0, 0.5), (FRAC_PI_2, -0.48121182505960347)),
+        ((1.0, 1.0), (0.9045568943023814, -1.0612750619050357)),
+        ((1.0, 0.0), (0.0, -0.0)),
+        ((1.0, -0.0), (0.0, 0.0)),
+        ((-1.0, 0.0), (PI, -0.0)),
+        ((-1.0, -0.0), (PI, 0.0)),
+    ];
+    let arctan = [
+        (
+            (1.0000000009313226, 1e-12),
+            (0.7853981638631096, 4.999999995343387e-13),
+        ),
+        ((1.0, 1e-300), (FRAC_PI_4, 5e-301)),
+        ((0.5, 1e-20), (0.4636476090008061, 8e-21)),
+        ((1e+300, 1e+300), (FRAC_PI_2, 5e-301)),
+        ((1e-300, 1e-300), (1e-300, 1e-300)),
+        ((1e-08, 100000000.0), (FRAC_PI_2, 1e-08)),
+        ((2.0, 1e-17), (1.1071487177940904, 2e-18)),
+        (
+            (0.9999999, 1e-07),
+            (0.7853981133974484, 5.000000500000016e-08),
+        ),
+        ((1e-05, 1.0), (0.7854006633974483, 6.103036322771337)),
+        ((-10000000000.0, 3.0), (-1.5707963266948965, 3e-20)),
+        ((1e-200, 1.0), (FRAC_PI_4, 230.60508288968455)),
+        ((0.3, -0.4), (0.3373704711117763, -0.37908687234202215)),
+        (
+            (-0.999, 0.001),
+            (-0.7848981635641149, 0.0005005001666667668),
+        ),
+        ((1e+200, 1e-200), (FRAC_PI_2, 0.0)),
+        ((0.0, 0.5), (0.0, 0.5493061443340549)),
+        ((1.0, 1.0), (1.0172219678978514, 0.40235947810852507)),
+    ];
+    let arcsinh = [
+        (
+            (1.0000000009313226, 1e-12),
+            (0.8813735876780875, 7.071067808572753e-13),
+        ),
+        ((1.0, 1e-300), (0.881373587019543, 7.071067811865475e-301)),
+        ((0.5, 1e-20), (0.48121182505960347, 8.944271909999158e-21)),
+        ((1e+300, 1e+300), (691.8152486690536, FRAC_PI_4)),
+        ((1e-300, 1e-300), (1e-300, 1e-300)),
+        ((1e-08, 100000000.0), (19.11382792451231, FRAC_PI_2)),
+        ((2.0, 1e-17), (1.4436354751788103, 4.4721359549995795e-18)),
+        (
+            (0.9999999, 1e-07),
+            (0.881373516308865, 7.071068165418872e-08),
+        ),
+        ((1e-05, 1.0), (0.0031622802953938335, 1.5676340517699656)),
+        ((-10000000000.0, 3.0), (-23.7189981105004, 3e-10)),
+        ((1e-200, 1.0), (1e-100, FRAC_PI_2)),
+        ((0.3, -0.4), (0.3189624333048184, -0.3903162045220237)),
+        (
+            (-0.999, 0.001),
+            (-0.8806664802972378, 0.0007074603935027634),
+        ),
+        ((1e+200, 1e-200), (461.2101657793691, 0.0)),
+        ((0.0, 0.5), (0.0, FRAC_PI_6)),
+        ((1.0, 1.0), (1.0612750619050357, 0.6662394324925153)),
+        ((1.0, 0.0), (0.881373587019543, 0.0)),
+        ((-1.0, -0.0), (-0.881373587019543, -0.0)),
+    ];
+    let arccosh = [
+        (
+            (1.0000000009313226, 1e-12),
+            (4.315837909158074e-05, 2.3170471661310312e-08),
+        ),
+        ((1.0, 1e-300), (1e-150, 1e-150)),
+        ((0.5, 1e-20), (1.1547005383792515e-20, FRAC_PI_3)),
+        ((1e+300, 1e+300), (691.8152486690536, FRAC_PI_4)),
+        ((1e-300, 1e-300), (1e-300, FRAC_PI_2)),
+        ((1e-08, 100000000.0), (19.11382792451231, FRAC_PI_2)),
+        ((2.0, 1e-17), (1.3169578969248168, 5.773502691896258e-18)),
+        (
+            (0.9999999, 1e-07),
+            (0.00020352237864605657, 0.0004913464750097201),
+        ),
+        ((1e-05, 1.0), (0.8813735870372207, 1.5707892557270848)),
+        ((-10000000000.0, 3.0), (23.7189981105004, 3.141592653289793)),
+        ((1e-200, 1.0), (0.881373587019543, FRAC_PI_2)),
+        ((0.3, -0.4), (0.4051123371780309, -1.2901667645030908)),
+        ((-0.999, 0.001), (0.020358029698343834, 3.09245560854935)),
+        ((1e+200, 1e-200), (461.2101657793691, 0.0)),
+        ((0.0, 0.5), (0.48121182505960347, FRAC_PI_2)),
+        ((1.0, 1.0), (1.0612750619050357, 0.9045568943023814)),
+        ((1.0, 0.0), (0.0, 0.0)),
+        ((1.0, -0.0), (0.0, -0.0)),
+        ((-1.0, 0.0), (0.0, PI)),
+        ((-1.0, -0.0), (0.0, -PI)),
+    ];
+    let arctanh = [
+        (
+            (1.0000000009313226, 1e-12),
+            (10.743781010681772, 1.5702594560894698),
+        ),
+        ((1.0, 1e-300), (345.73433753938684, FRAC_PI_4)),
+        ((0.5, 1e-20), (0.5493061443340549, 1.3333333333333333e-20)),
+        ((1e+300, 1e+300), (5e-301, FRAC_PI_2)),
+        ((1e-300, 1e-300), (1e-300, 1e-300)),
+        ((1e-08, 100000000.0), (1e-24, 1.5707963167948966)),
+        ((2.0, 1e-17), (0.5493061443340549, FRAC_PI_2)),
+        ((0.9999999, 1e-07), (8.232334595750736, 0.39269910683031434)),
+        ((1e-05, 1.0), (4.999999999916667e-06, 0.7853981634224483)),
+        ((-10000000000.0, 3.0), (-1e-10, FRAC_PI_2)),
+        ((1e-200, 1.0), (5e-201, FRAC_PI_4)),
+        ((0.3, -0.4), (0.2614921387956719, -0.4088225229163511)),
+        ((-0.999, 0.001), (-3.6269144346727527, 0.3929492067403906)),
+        ((1e+200, 1e-200), (1e-200, FRAC_PI_2)),
+        ((0.0, 0.5), (0.0, 0.4636476090008061)),
+        ((1.0, 1.0), (0.40235947810852507, 1.0172219678978514)),
+    ];
+
+    check_reference_values("arcsin", Complex::arcsin, &arcsin);
+    check_reference_values("arccos", Complex::arccos, &arccos);
+    check_reference_values("arctan", Complex::arctan, &arctan);
+    check_reference_values("arcsinh", Complex::arcsinh, &arcsinh);
+    check_reference_values("arccosh", Complex::arccosh, &arccosh);
+    check_reference_values("arctanh", Complex::arctanh, &arctanh);
+
+    // the branch points are finite, with the signs of the zeros from C99
+    for (table, function) in [
+        (
+            &arccos[..],
+            Complex::arccos as fn(Complex<f64>) -> Complex<f64>,
+        ),
+        (&arccosh[..], Complex::arccosh),
+    ] {
+        for &((x, y), (real, imag)) in table.iter().filter(|(z, _)| z.0.abs() == 1.0 && z.1 == 0.0)
+        {
+            let result = function(Complex::new(x, y));
+            assert_eq!(result.real().is_sign_negative(), real.is_sign_negative());
+            assert_eq!(result.imag().is_sign_negative(), imag.is_sign_negative());
+        }
+    }
+    assert_eq!(Complex::new(1.0, 0.0).arcsec(), Complex::new(0.0, 0.0));
+    assert_eq!(Complex::new(1.0, 0.0).arcsech(), Complex::new(0.0, 0.0));
+}
+
+#[test]
+fn cancellation_free_functions() {
+    // Reference values computed with 2000 bit precision. The real part of cos_m1 is badly
+    // conditioned near |x| = |y|, so those points are left out
+    let ln_1p = [
+        ((1e-10, 1e-10), (1e-10, 9.999999999e-11)),
+        ((1e-20, -3e-20), (1e-20, -3e-20)),
+        ((0.3, -0.2), (0.2740607042548438, -0.15264932839526518)),
+        (
+            (-1e-08, 0.0002),
+            (9.999999950000003e-09, 0.00019999999933333334),
+        ),
+        ((0.1, 0.1), (0.0994254293725826, 0.09065988720074512)),
+        ((-0.4, 0.45), (-0.28768207245178096, 0.6435011087932844)),
+        ((2.5, -1.0), (1.2919987762161156, -0.27829965900511133)),
+        ((1e-300, 1e-300), (1e-300, 1e-300)),
+        (
+            (-1e-05, 0.001),
+            (-9.500040250193168e-06, 0.0010000096667568674),
+        ),
+    ];
+    let exp_m1 = [
+        ((1e-10, 1e-10), (1e-10, 1.0000000001000001e-10)),
+        ((1e-20, -3e-20), (1e-20, -3e-20)),
+        ((0.3, -0.2), (0.32295150210987245, -0.26817554596894383)),
+        (
+            (-1e-08, 0.0002),
+            (-2.9999999683333335e-08, 0.0001999999966666667),
+        ),
+        ((0.1, 0.1), (0.09964966682940916, 0.11033298873020372)),
+        ((-0.4, 0.45), (-0.3964122568982956, 0.2915661168493563)),
+        ((2.5, -1.0), (5.582229578192773, -10.251215190529404)),
+        ((1e-300, 1e-300), (1e-300, 1e-300)),
+        (
+            (-1e-05, 0.001),
+            (-1.0499944958525419e-05, 0.000999989833385008),
+        ),
+    ];
+    let cos_m1 = [
+        ((1e-20, -3e-20), (4.0000000000000005e-40, 3e-40)),
+        ((0.3, -0.2), (-0.02549300701312453, 0.05949885707931209)),
+        (
+            (-1e-08, 0.0002),
+            (2.0000000016666667e-08, 2.0000000133333335e-12),
+        ),
+        ((2.5, -1.0), (-2.236229198856342, 0.7033251781135348)),
+        ((1e-300, 1e-300), (0.0, -0.0)),
+        (
+            (-1e-05, 0.001),
+            (4.999500416416684e-07, 1.0000001666500084e-08),
+        ),
+    ];
+    let ln_abs = [
+        ((0.6, 0.8), 2.2204460492503132e-17),
+        (
+            (1.0000000000009095, 9.313225746154785e-10),
+            9.094951354533836e-13,
+        ),
+        ((0.9999999999, 1e-05), -5.0000008271537093e-11),
+        ((1.0, 1e-10), 5.0000000000000005e-21),
+        ((0.8, -0.6000000000000001), 8.881784197001252e-17),
+        ((3.0, 4.0), 1.6094379124341003),
+        ((1e-300, 0.0), -690.7755278982137),
+    ];
+
+    check_reference_values("ln_1p", Complex::ln_1p, &ln_1p);
+    check_reference_values("exp_m1", Complex::exp_m1, &exp_m1);
+    check_reference_values("cos_m1", Complex::cos_m1, &cos_m1);
+    for ((x, y), expected) in ln_abs {
+        let result = Complex::new(x, y).ln_abs();
+        assert!(
+            Complex::new(result, 0f64).ulps_eq(Complex::new(expected, 0f64), 4),
+            "ln_abs({:?}) returned {:?}, expected {:?}",
+            (x, y),
+            result,
+            expected
+        );
+    }
+
+    // special values go through the plain functions
+    assert_eq!(
+        Complex::new(f64::NEG_INFINITY, 1f64).exp_m1(),
+        Complex::new(-1f64, 0f64)
+    );
+    assert!(Complex::new(f64::NAN, 0f64).ln_1p().real().is_nan());
+    assert_eq!(Complex::new(-1f64, 0f64).ln_1p().real(), f64::NEG_INFINITY);
+}
+
+#[test]
+fn multi_valued_functions() {
+    use f64::consts::PI;
+    let z = Complex::new(-3f64, 4f64);
+
+    // nth_root
+    assert_complex_eq!(
+        Complex::new(-8f64, 0f64).nth_root(3),
+        Complex::new(1f64, 3f64.sqrt()),
+        1e-13
+    );
+    assert_eq!(z.nth_root(2), z.sqrt());
+    assert_eq!(z.nth_root(1), z);
+    assert!(z.nth_root(0).real().is_nan());
+
+    // roots
+    let roots: Vec<_> = z.roots(5).collect();
+    assert_eq!(roots.len(), 5);
+    assert_eq!(roots[0], z.nth_root(5));
+    for root in &roots {
+        assert_complex_eq!(root.powi(5), z, 1e-13);
+    }
+    assert_complex_eq!(roots.iter().sum(), Complex::new(0f64, 0f64), 1e-13);
+    assert_eq!(z.roots(3).len(), 3);
+    assert_eq!(z.roots(0).next(), None);
+
+    // roots of unity
+    let unity: Vec<Complex<f64>> = Complex::roots_of_unity(6).collect();
+    assert_eq!(unity[0], Complex::new(1f64, 0f64));
+    assert_eq!(unity[3], Complex::new(-1f64, 0f64));
+    assert_eq!(unity[1], unity[5].conj());
+    assert_eq!(unity[2], unity[4].conj());
+    assert_complex_eq!(unity[1], Complex::new(0.5, 3f64.sqrt() / 2f64), 1e-13);
+
+    // ln_branch
+    assert_eq!(z.ln_branch(0), z.ln());
+    for k in -3..=3 {
+        let ln = z.ln_branch(k);
+        assert_complex_eq!(ln.exp(), z, 1e-13);
+        assert!((ln.imag() - z.arg() - 2f64 * PI * k as f64).abs() < 1e-13);
+    }
+
+    // powc_branch
+    let third = Complex::new(1f64 / 3f64, 0f64);
+    for (k, root) in z.roots(3).enumerate() {
+        assert_complex_eq!(z.powc_branch(third, k as i64), root, 1e-13);
+    }
+    assert_complex_eq!(
+        z.powc_branch(Complex::new(2f64, 0.5), 0),
+        z.powc(Complex::new(2f64, 0.5)),
+        1e-13
+    );
+}
+
+#[test]
+fn checked_math() {
+    use crate::ComplexError;
+    let z = Complex::new(3f64, 4f64);
+    let zero = Complex::new(0f64, 0f64);
+    let one = Complex::new(1f64, 0f64);
+    let nan = Complex::new(f64::NAN, 0f64);
+    let big = Complex::new(1e308, 1e308);
+
+    // results match the unchecked functions
+    assert_eq!(z.checked_add(z), Ok(z + z));
+    assert_eq!(z.checked_sub(one), Ok(z - one));
+    assert_eq!(z.checked_mul(z), Ok(z * z));
+    assert_eq!(z.checked_div(z), Ok(z / z));
+    assert_eq!(z.checked_rem(one), Ok(z % one));
+    assert_eq!(z.checked_inv(), Ok(z.inv()));
+    assert_eq!(z.checked_exp(), Ok(z.exp()));
+    assert_eq!(z.checked_ln(), Ok(z.ln()));
+    assert_eq!(z.checked_log(), Ok(z.log()));
+    assert_eq!(z.checked_logn(2f64), Ok(z.logn(2f64)));
+    assert_eq!(z.checked_powi(-3), Ok(z.powi(-3)));
+    assert_eq!(z.checked_powf(0.5), Ok(z.powf(0.5)));
+    assert_eq!(z.checked_powc(z), Ok(z.powc(z)));
+    assert_eq!(z.checked_sqrt(), Ok(z.sqrt()));
+    assert_eq!(z.checked_arctanh(), Ok(z.arctanh()));
+
+    // division by zero
+    assert_eq!(z.checked_div(zero), Err(ComplexError::DivisionByZero));
+    assert_eq!(z.checked_rem(zero), Err(ComplexError::DivisionByZero));
+    assert_eq!(zero.checked_inv(), Err(ComplexError::DivisionByZero));
+    assert_eq!(z.checked_logn(1f64), Err(ComplexError::DivisionByZero));
+    assert_eq!(z.checked_logn(-0f64), Err(ComplexError::Pole));
+    assert_eq!(z.checked_logn(f64::INFINITY), Ok(Complex::<f64>::ZERO));
+
+    // poles
+    assert_eq!(zero.checked_ln(), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_log(), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_powi(-1), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_powf(-0.5), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_cot(), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_csc(), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_coth(), Err(ComplexError::Pole));
+    assert_eq!(zero.checked_csch(), Err(ComplexError::Pole));
+    assert_eq!(
+        Complex::new(0f64, -1f64).checked_arctan(),
+        Err(ComplexError::Pole)
+    );
+    assert_eq!(one.checked_arctanh(), Err(ComplexError::Pole));
+
+    // powers of zero
+    assert_eq!(zero.checked_powi(2), Ok(zero));
+    assert_eq!(zero.checked_powf(0f64), Ok(one));
+    assert_eq!(zero.checked_powc(zero), Ok(one));
+
+    // overflow
+    assert_eq!(big.checked_add(big), Err(ComplexError::Overflow));
+    assert_eq!(big.checked_mul(big), Err(ComplexError::Overflow));
+    assert_eq!(
+        Complex::new(1e300, 0f64).checked_div(Complex::new(1e-300, 0f64)),
+        Err(ComplexError::Overflow)
+    );
+    assert_eq!(
+        Complex::new(1000f64, 0f64).checked_exp(),
+        Err(ComplexError::Overflow)
+    );
+    assert_eq!(big.checked_powi(2), Err(ComplexError::Overflow));
+
+    // infinite arguments are not an overflow
+    let infinity = Complex::new(f64::INFINITY, 0f64);
+    assert_eq!(infinity.checked_add(one), Ok(infinity));
+
+    // NaN
+    assert_eq!(nan.checked_add(one), Err(ComplexError::NaN));
+    assert_eq!(z.checked_div(nan), Err(ComplexError::NaN));
+    assert_eq!(nan.checked_div(zero), Err(ComplexError::NaN));
+    assert_eq!(nan.checked_arctanh(), Err(ComplexError::NaN));
+    assert_eq!(infinity.checked_sub(infinity), Err(ComplexError::NaN));
+    assert_eq!(z.checked_logn(-2f64), Err(ComplexError::NaN));
+
+    assert_eq!(
+        ComplexError::Pole.to_string(),
+        "function evaluated at a pole"
+    );
+}
+
+#[test]
+fn approximate_equality() {
+    let z = Complex::new(0.1, 0.2) + Complex::new(0.2, 0.1);
+    let expected = Complex::new(0.3, 0.3);
+
+    // approx_eq
+    assert!(z.approx_eq(expected, 1e-15, 0f64));
+    assert!(z.approx_eq(expected, 0f64, 1e-15));
+    assert!(!z.approx_eq(expected, 0f64, 0f64));
+    assert!(!Complex::new(1e6, 0f64).approx_eq(Complex::new(1e6 + 1f64, 0f64), 1e-3, 1e-9));
+    assert!(Complex::new(1e6, 0f64).approx_eq(Complex::new(1e6 + 1f64, 0f64), 1e-3, 1e-6));
+    let infinity = Complex::new(f64::INFINITY, 0f64);
+    assert!(infinity.approx_eq(infinity, 0f64, 0f64));
+    let nan = Complex::new(f64::NAN, 0f64);
+    assert!(!nan.approx_eq(nan, f64::INFINITY, f64::INFINITY));
+
+    // ulps
+    let one = Complex::new(1f64, 1f64);
+    let next = Complex::new(1f64, f64::from_bits(1f64.to_bits() + 1));
+    assert_eq!(one.ulp_distance(next), 1);
+    assert_eq!(
+        Complex::new(0f64, -0f64).ulp_distance(Complex::new(-0f64, 0f64)),
+        0
+    );
+    assert_eq!(
+        Complex::new(-f64::from_bits(1), 0f64).ulp_distance(Complex::new(f64::from_bits(1), 0f64)),
+        2
+    );
+    assert_eq!(nan.ulp_distance(nan), u64::MAX);
+    assert!(z.ulps_eq(expected, 1));
+    assert!(Complex::new(1f32, 2f32).ulps_eq(Complex::new(1f32, 2.0000002), 1));
+
+    // assertion macros
+    assert_complex_eq!(z, expected);
+    assert_complex_eq!(z, Complex::new(0.3001, 0.3), 1e-3);
+    assert_complex_relative_eq!(z * 1e100, expected * 1e100);
+    assert_complex_relative_eq!(Complex::new(1f32, 1f32), Complex::new(1.01f32, 1f32), 1e-2);
+    assert!(std::panic::catch_unwind(|| assert_complex_eq!(z * 1e100, expected * 1e100)).is_err());
+    assert!(std::panic::catch_unwind(|| assert_complex_relative_eq!(z, expected, 0f64)).is_err());
+}
+
+#[test]
+fn total_ordering() {
+    use crate::TotalComplex;
+    use std::cmp::Ordering;
+    use std::collections::{BTreeMap, HashMap};
+
+    let nan = f64::NAN;
+    let other_nan = f64::from_bits(0x7ff8_0000_0000_0001);
+
+    // equality and hashing canonicalise the zeros and NaNs
+    let mut counts = HashMap::new();
+    for z in [
+        Complex::new(0f64, 1f64),
+        Complex::new(-0f64, 1f64),
+        Complex::new(nan, 2f64),
+        Complex::new(-other_nan, 2f64),
+        Complex::new(3f64, -0f64),
+    ] {
+        *counts.entry(TotalComplex::new(z)).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&TotalComplex::new(Complex::new(0f64, 1f64))], 2);
+    assert_eq!(counts[&TotalComplex::new(Complex::new(nan, 2f64))], 2);
+    assert_eq!(counts[&TotalComplex::new(Complex::new(3f64, 0f64))], 1);
+
+    // lexicographic order, NaN after infinity
+    let map: BTreeMap<_, _> = [
+        (Complex::new(1f64, nan), 'a'),
+        (Complex::new(1f64, f64::INFINITY), 'b'),
+        (Complex::new(-1f64, 5f64), 'c'),
+        (Complex::new(1f64, -2f64), 'd'),
+        (Complex::new(f64::NEG_INFINITY, 0f64), 'e'),
+    ]
+    .into_iter()
+    .map(|(z, value)| (TotalComplex::from(z), value))
+    .collect();
+    assert_eq!(map.values().collect::<String>(), "ecdba");
+    assert_eq!(
+        Complex::from(*map.keys().next().unwrap()),
+        Complex::new(f64::NEG_INFINITY, 0f64)
+    );
+
+    // comparators
+    let mut numbers = vec![
+        Complex::new(3f64, 4f64),
+        Complex::new(-5f64, 0f64),
+        Complex::new(0f64, 1f64),
+        Complex::new(1f64, -1f64),
+        Complex::new(-1f64, -0f64),
+    ];
+    numbers.sort_by(Complex::cmp_by_modulus);
+    assert_eq!(
+        numbers,
+        [
+            Complex::new(-1f64, -0f64),
+            Complex::new(0f64, 1f64),
+            Complex::new(1f64, -1f64),
+            Complex::new(3f64, 4f64),
+            Complex::new(-5f64, 0f64),
+        ]
+    );
+    numbers.sort_by(Complex::cmp_by_arg);
+    assert_eq!(
+        numbers,
+        [
+            Complex::new(-1f64, -0f64),
+            Complex::new(1f64, -1f64),
+            Complex::new(3f64, 4f64),
+            Complex::new(0f64, 1f64),
+            Complex::new(-5f64, 0f64),
+        ]
+    );
+    numbers.sort_by(Complex::total_cmp);
+    assert_eq!(numbers[0], Complex::new(-5f64, 0f64));
+    assert_eq!(numbers[4], Complex::new(3f64, 4f64));
+    assert_eq!(
+        Complex::new(nan, 0f64).total_cmp(&Complex::new(f64::INFINITY, 0f64)),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn constants() {
+    const Z: Complex<f64> = Complex::new(3f64, 4f64);
+    const W: Complex<f32> = c!(1.5 - 2.5 i);
+    static ROOTS: [Complex<f64>; 4] = [
+        Complex::<f64>::ONE,
+        Complex::<f64>::I,
+        c!(-1.0),
+        Complex::<f64>::NEG_I,
+    ];
+
+    assert_eq!(Z, Complex::new(3f64, 4f64));
+    assert_eq!(W, Complex::new(1.5f32, -2.5f32));
+    assert_eq!(ROOTS.iter().sum::<Complex<f64>>(), Complex::<f64>::ZERO);
+    assert_eq!(ROOTS.iter().product::<Complex<f64>>(), -Complex::<f64>::ONE);
+
+    // the constants match their generic counterparts
+    assert_eq!(Complex::<f64>::I, Complex::i());
+    assert_eq!(
+        Complex::<f64>::ONE,
+        <Complex<f64> as num_traits::ConstOne>::ONE
+    );
+    assert_eq!(
+        Complex::<f32>::ZERO,
+        <Complex<f32> as num_traits::ConstZero>::ZERO
+    );
+    assert_eq!(Complex::<f32>::I * Complex::<f32>::I, -Complex::<f32>::ONE);
+    assert!(Complex::<f64>::NAN.real().is_nan() && Complex::<f64>::NAN.imag().is_nan());
+    assert_eq!(Complex::<f32>::INFINITY.real(), f32::INFINITY);
+
+    // c!
+    assert_eq!(c!(3.0 + 4.0 i), Z);
+    assert_eq!(c!(-3.0 - 4.0 i), -Z);
+    assert_eq!(c!(2.0 + i), Complex::new(2f64, 1f64));
+    assert_eq!(c!(2.0 - i), Complex::new(2f64, -1f64));
+    assert_eq!(c!(4.0 i), Complex::new(0f64, 4f64));
+    assert_eq!(c!(i), Complex::<f64>::I);
+    assert_eq!(c!(-i), Complex::<f64>::NEG_I);
+    assert_eq!(c!(7.0), Complex::new(7f64, 0f64));
+    assert!(c!(1.0 - 0.0 i).imag().is_sign_negative());
+}
+
+#[test]
+fn classification_and_components() {
+    use core::num::FpCategory;
+
+    let inf = f64::INFINITY;
+    let nan = f64::NAN;
+
+    // classification, an infinite part wins over a NaN part
+    let cases = [
+        (Complex::new(1f64, -2f64), FpCategory::Normal),
+        (Complex::new(1f64, 0f64), FpCategory::Normal),
+        (Complex::new(0f64, -0f64), FpCategory::Zero),
+        (Complex::new(1f64, 1e-310), FpCategory::Subnormal),
+        (Complex::new(1f64, nan), FpCategory::Nan),
+        (Complex::new(nan, -inf), FpCategory::Infinite),
+        (Complex::new(inf, 1f64), FpCategory::Infinite),
+    ];
+    for (z, category) in cases {
+        assert_eq!(z.classify(), category, "{z:?}");
+        assert_eq!(z.is_normal(), category == FpCategory::Normal);
+        assert_eq!(z.is_nan(), category == FpCategory::Nan);
+        assert_eq!(z.is_infinite(), category == FpCategory::Infinite);
+        assert_eq!(
+            z.is_finite(),
+            category != FpCategory::Nan && category != FpCategory::Infinite
+        );
+    }
+    assert!(Complex::new(2f32, 0f32).is_real() && !Complex::new(2f32, 0f32).is_imaginary());
+    assert!(Complex::new(0f32, 2f32).is_imaginary() && !Complex::new(0f32, 2f32).is_real());
+    assert!(Complex::new(0f32, -0f32).is_real() && Complex::new(0f32, -0f32).is_imaginary());
+
+    // projection
+    let projected = Complex::new(nan, -inf).proj();
+    assert_eq!(projected, Complex::new(inf, 0f64));
+    assert!(projected.imag().is_sign_negative());
+    assert!(Complex::new(-inf, 0f64).proj().imag().is_sign_positive());
+    assert_eq!(Complex::new(3f64, -4f64).proj(), Complex::new(3f64, -4f64));
+    assert!(Complex::new(nan, 1f64).proj().real().is_nan());
+
+    // rounding
+    let z = Complex::new(2.5f64, -1.75f64);
+    assert_eq!(z.floor(), Complex::new(2f64, -2f64));
+    assert_eq!(z.ceil(), Complex::new(3f64, -1f64));
+    assert_eq!(z.round(), Complex::new(3f64, -2f64));
+    assert_eq!(z.trunc(), Complex::new(2f64, -1f64));
+    assert_eq!(z.fract(), Complex::new(0.5f64, -0.75f64));
+    assert_eq!(z.trunc() + z.fract(), z);
+
+    // norms
+    assert_eq!(z.l1_norm(), 4.25);
+    assert_eq!(z.max_norm(), 2.5);
+    assert_eq!(Complex::new(nan, inf).l1_norm(), inf);
+    assert_eq!(Complex::new(nan, inf).max_norm(), inf);
+    assert!(Complex::new(nan, 1f64).l1_norm().is_nan());
+    assert!(Complex::new(nan, 1f64).max_norm().is_nan());
+
+    // scaling and component access
+    assert_eq!(z.scale(2f64), Complex::new(5f64, -3.5f64));
+    assert_eq!(z.scale(4f64).unscale(4f64), z);
+    let mut w = Complex::new(1f64, 2f64);
+    w.set_real(-1f64);
+    *w.imag_mut() *= 3f64;
+    assert_eq!(w, Complex::new(-1f64, 6f64));
+    *w.real_mut() = 5f64;
+    w.set_imag(0f64);
+    let (real, imag) = w.parts_mut();
+    core::mem::swap(real, imag);
+    assert_eq!(w, Complex::new(0f64, 5f64));
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn integer_components() {
+    use num_traits::{
+        CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, One, WrappingAdd, WrappingMul, WrappingNeg,
+        WrappingSub, Zero,
+    };
+    use std::collections::HashSet;
+    use std::num::Wrapping;
+
+    let z1 = Complex::new(3i64, 4i64);
+    let z2 = Complex::new(-2i64, 5i64);
+
+    // operators
+    assert_eq!(z1 + z2, Complex::new(1, 9));
+    assert_eq!(z1 - z2, Complex::new(5, -1));
+    assert_eq!(z1 * z2, Complex::new(-26, 7));
+    assert_eq!(-z1, Complex::new(-3, -4));
+    assert_eq!(z1 * 3, Complex::new(9, 12));
+    assert_eq!(z1 / 2, Complex::new(1, 2));
+    assert_eq!(&z1 * &z2 + 1, Complex::new(-25, 7));
+    let mut z = z1;
+    z *= z2;
+    z -= &z1;
+    assert_eq!(z, Complex::new(-29, 3));
+    assert_eq!(Complex::<i64>::i() * Complex::i(), -Complex::one());
+    assert!((z1 - z1).is_zero());
+    assert_eq!(
+        [z1, z2, z1].iter().sum::<Complex<i64>>(),
+        Complex::new(4, 13)
+    );
+    assert_eq!(
+        [z1, z1.conj()].into_iter().product::<Complex<i64>>(),
+        Complex::new(25, 0)
+    );
+
+    // exact helpers
+    assert_eq!(z1.norm(), 25);
+    assert_eq!(z2.conj(), Complex::new(-2, -5));
+    assert_eq!(z1.real() + z1.imag(), 7);
+    assert_eq!([z1, z2].map(Complex::real), [3, -2]);
+    let exact = Complex::new(String::from("3"), String::from("4"));
+    assert_eq!(
+        (exact.real_ref().as_str(), exact.imag_ref().as_str()),
+        ("3", "4")
+    );
+    let mut z = Complex::new(1i64, 2);
+    z.set_real(5);
+    *z.imag_mut() -= 3;
+    assert_eq!(z, Complex::new(5, -1));
+    let mut exact = exact;
+    exact.real_mut().push('0');
+    exact.set_imag(String::from("-4"));
+    assert_eq!(exact, Complex::new(String::from("30"), String::from("-4")));
+    assert_eq!(Complex::new(1.5f64, 2f64).norm(), 6.25);
+    assert_eq!(HashSet::from([z1, z2, z1]).len(), 2);
+
+    // exact division, with the parts of integer quotients rounded towards zero
+    assert_eq!(Complex::new(-26, 7) / z1, z2);
+    assert_eq!(Complex::new(7, 3) / Complex::new(2, 1), Complex::new(3, 0));
+    assert_eq!(Complex::new(7, 3) % Complex::new(2, 1), Complex::new(1, 0));
+    let mut z = Complex::new(7, 3);
+    z %= &Complex::new(2, 1);
+    z /= Complex::new(1, 1);
+    assert_eq!(z, Complex::new(0, 0));
+    fn ratio<N: num_traits::Num>(numerator: N, denominator: N) -> N {
+        numerator / denominator
+    }
+    assert_eq!(ratio(z1 * z2, z2), z1);
+    assert_eq!(
+        <Complex<i64> as num_traits::Num>::from_str_radix("11 - 10i", 2),
+        Ok(Complex::new(3, -2))
+    );
+    let wrapping = Complex::new(Wrapping(i32::MAX), Wrapping(1));
+    assert_eq!(wrapping * wrapping, Complex::new(Wrapping(0), Wrapping(-2)));
+    assert_eq!(
+        Complex::new(Wrapping(7), Wrapping(3)) / Complex::new(Wrapping(2), Wrapping(1)),
+        Complex::new(Wrapping(3), Wrapping(0))
+    );
+    #[cfg(feature = "num-bigint")]
+    {
+        use num_bigint::BigUint;
+        let z = Complex::new(BigUint::from(3u32), BigUint::from(4u32));
+        let w = Complex::new(BigUint::from(5u32), BigUint::from(2u32));
+        assert_eq!(
+            &z * &w,
+            Complex::new(BigUint::from(7u32), BigUint::from(26u32))
+        );
+        assert_eq!(z.clone() * w.clone() / w, z);
+    }
+    #[cfg(feature = "num-rational")]
+    {
+        use num_rational::Ratio;
+        let third = Complex::new(Ratio::new(1i64, 3), Ratio::new(-2, 3));
+        let w = Complex::new(Ratio::from(2), Ratio::new(1, 2));
+        assert_eq!(third / w * w, third);
+        assert!((third % w).is_zero());
+        assert_eq!(ratio(third, third), Complex::one());
+    }
+
+    // checked and wrapping arithmetic
+    let big = Complex::new(i32::MAX, 1i32);
+    let one = Complex::new(1i32, 0i32);
+    assert_eq!(big.checked_add(&one), None);
+    assert_eq!(big.checked_sub(&one), Some(Complex::new(i32::MAX - 1, 1)));
+    assert_eq!(
+        big.checked_mul(&Complex::new(0, 1)),
+        Some(Complex::new(-1, i32::MAX))
+    );
+    assert_eq!(big.checked_mul(&big), None);
+    assert_eq!(Complex::new(i32::MIN, 0).checked_neg(), None);
+    assert_eq!(big.wrapping_add(&one), Complex::new(i32::MIN, 1));
+    assert_eq!(
+        Complex::new(i32::MIN, 0).wrapping_sub(&one),
+        Complex::new(i32::MAX, 0)
+    );
+    assert_eq!(big.wrapping_mul(&Complex::new(2, 0)), Complex::new(-2, 2));
+    assert_eq!(
+        Complex::new(i32::MIN, 3).wrapping_neg(),
+        Complex::new(i32::MIN, -3)
+    );
+    assert_eq!(
+        Complex::new(250u8, 1u8).wrapping_add(&Complex::new(10, 0)),
+        Complex::new(4, 1)
+    );
+
+    // the float methods of the same name still take priority for float parts
+    assert_eq!(
+        Complex::new(1f64, 2f64).checked_add(Complex::new(1f64, 0f64)),
+        Ok(Complex::new(2f64, 2f64))
+    );
+}
+
+#[test]
+fn gaussian_integers() {
+    use crate::gaussian::{GaussianInt, sum_of_two_squares};
+
+    let grid =
+        || (-8i64..=8).flat_map(|real| (-8i64..=8).map(move |imag| GaussianInt::new(real, imag)));
+    // Brute force definition of a Gaussian prime, no divisors of smaller norm besides units
+    let brute_is_prime = |z: GaussianInt| {
+        z.norm() > 1 && !grid().any(|d| d.norm() > 1 && d.norm() < z.norm() && d.divides(z))
+    };
+
+    // operators and division with remainder
+    let z = GaussianInt::new(7, 3);
+    let w = GaussianInt::new(2, -1);
+    assert_eq!(z + w, GaussianInt::new(9, 2));
+    assert_eq!(z - w, GaussianInt::new(5, 4));
+    assert_eq!(-z * GaussianInt::I, GaussianInt::new(3, -7));
+    assert_eq!(z.pow(3), z * z * z);
+    assert_eq!(GaussianInt::new(3, 4).to_string(), "3 + 4i");
+    assert_eq!(GaussianInt::new(3, -4).to_string(), "3 - 4i");
+    for z in grid() {
+        for w in grid().filter(|&w| w != GaussianInt::ZERO) {
+            let (quotient, remainder) = z.div_rem(w);
+            assert_eq!(quotient * w + remainder, z);
+            assert!(remainder.norm() * 2 <= w.norm(), "{z} % {w}");
+            assert_eq!((z / w, z % w), (quotient, remainder));
+        }
+    }
+    let mut v = GaussianInt::new(27, -23);
+    v %= GaussianInt::new(8, 1);
+    assert!(v.norm() * 2 <= 65);
+    let z = GaussianInt::new(i64::MIN, i64::MAX);
+    assert_eq!(
+        z.div_rem(-GaussianInt::I),
+        (GaussianInt::new(-i64::MAX, i64::MIN), GaussianInt::ZERO)
+    );
+    assert!(
+        std::panic::catch_unwind(|| GaussianInt::new(i64::MIN, 0) / -GaussianInt::ONE).is_err()
+    );
+
+    // associates and normalisation
+    let associates = GaussianInt::new(-3, 2).associates();
+    assert_eq!(associates[0], GaussianInt::new(-3, 2));
+    assert!(
+        associates
+            .iter()
+            .all(|&a| a.is_associate(GaussianInt::new(2, 3)))
+    );
+    assert!(!GaussianInt::new(2, 3).is_associate(GaussianInt::new(3, 2)));
+    assert!(
+        GaussianInt::UNITS
+            .iter()
+            .all(|u| u.is_unit() && u.normalize() == GaussianInt::ONE)
+    );
+    for z in grid().filter(|&z| z != GaussianInt::ZERO) {
+        let normalized = z.normalize();
+        assert!(normalized.real() > 0 && normalized.imag() >= 0, "{z}");
+        assert!(z.is_associate(normalized));
+    }
+
+    // gcd and Bézout coefficients
+    for (z, w) in grid()
+        .step_by(7)
+        .flat_map(|z| grid().step_by(11).map(move |w| (z, w)))
+    {
+        let g = z.gcd(w);
+        let (h, x, y) = z.xgcd(w);
+        assert_eq!(g, h);
+        assert_eq!(x * z + y * w, g, "xgcd({z}, {w})");
+        if g != GaussianInt::ZERO {
+            assert!(g.divides(z) && g.divides(w));
+            // any common divisor divides the gcd
+            assert!(
+                grid()
+                    .filter(|&d| d != GaussianInt::ZERO && d.divides(z) && d.divides(w))
+                    .all(|d| d.divides(g))
+            );
+        }
+    }
+    assert_eq!(GaussianInt::ZERO.gcd(GaussianInt::ZERO), GaussianInt::ZERO);
+    assert_eq!(
+        GaussianInt::ZERO.gcd(GaussianInt::new(0, -3)),
+        GaussianInt::new(3, 0)
+    );
+
+    // primes and factorisation
+    for z in grid().filter(|&z| z != GaussianInt::ZERO) {
+        assert_eq!(z.is_prime(), brute_is_prime(z), "{z}");
+        let factorization = z.factorize();
+        assert_eq!(factorization.product(), z, "{z}");
+        assert!(factorization.unit.is_unit());
+        for &(prime, _) in &factorization.factors {
+            assert!(
+                prime.is_prime() && prime == prime.normalize(),
+                "{z}: {prime}"
+            );
+        }
+    }
+    // 10^9 + 7 is a rational prime of the form 4k + 3, and 4 + 1_000_000_029i has a prime norm
+    let large_primes = [
+        GaussianInt::new(1_000_000_007, 0),
+        GaussianInt::new(4, 1_000_000_029),
+    ];
+    assert!(large_primes.iter().all(|prime| prime.is_prime()));
+    assert!(GaussianInt::new((1 << 61) - 1, 0).is_prime());
+    let big = GaussianInt::new(-2, 1).pow(5) * large_primes[0] * GaussianInt::new(3, 2);
+    let factorization = big.factorize();
+    assert_eq!(factorization.product(), big);
+    assert_eq!(
+        factorization.factors,
+        [
+            (GaussianInt::new(1, 2), 5),
+            (GaussianInt::new(3, 2), 1),
+            (large_primes[0], 1)
+        ]
+    );
+    let big = GaussianInt::new(0, 3).pow(4) * large_primes[1];
+    assert_eq!(
+        big.factorize().factors,
+        [(GaussianInt::new(3, 0), 4), (large_primes[1], 1)]
     );
+
+    // sums of two squares
+    for n in 0u64..2000 {
+        let brute = (0..=n.isqrt())
+            .flat_map(|a| (a..=n.isqrt()).map(move |b| (a, b)))
+            .find(|&(a, b)| a * a + b * b == n)
+            .is_some();
+        match sum_of_two_squares(n) {
+            Some((a, b)) => assert!(a <= b && a * a + b * b == n, "{n}"),
+            None => assert!(!brute, "{n}"),
+        }
+        assert_eq!(sum_of_two_squares(n).is_some(), brute, "{n}");
+    }
+    // the largest prime below 2^64 is 1 mod 4
+    let p = u64::MAX - 58;
+    let (a, b) = sum_of_two_squares(p).unwrap();
+    assert_eq!(a as u128 * a as u128 + b as u128 * b as u128, p as u128);
+    assert_eq!(sum_of_two_squares((1 << 61) - 1), None);
+    assert_eq!(
+        sum_of_two_squares(5 * 1_000_000_007 * 1_000_000_007),
+        Some((1_000_000_007, 2_000_000_014))
+    );
+}
+
+#[test]
+fn quadratic_integers() {
+    use crate::gaussian::GaussianInt;
+    use crate::quadratic::{Eisenstein, QuadraticInt};
+
+    let grid =
+        |range: i64| (-range..=range).flat_map(move |a| (-range..=range).map(move |b| (a, b)));
+
+    // Eisenstein integers agree with their complex values
+    let omega = Complex::<f64>::from(Eisenstein::OMEGA);
+    assert_complex_eq!(omega, Complex::new(-0.5, f64::sqrt(3f64) / 2f64));
+    assert_complex_eq!(omega.powi(3), Complex::new(1f64, 0f64));
+    for (z, w) in grid(4)
+        .map(|(a, b)| Eisenstein::new(a, b))
+        .zip(grid(4).rev().map(|(a, b)| Eisenstein::new(b, a)))
+    {
+        let (zc, wc) = (Complex::<f64>::from(z), Complex::<f64>::from(w));
+        assert_complex_eq!(Complex::from(z * w), zc * wc, 1e-12);
+        assert_complex_eq!(Complex::from(z - w), zc - wc, 1e-12);
+        assert_complex_eq!(Complex::from(z.conj()), zc.conj(), 1e-12);
+        assert!((z.norm() as f64 - zc.square_abs()).abs() < 1e-9);
+    }
+    assert!(Eisenstein::UNITS.iter().all(|u| u.is_unit()));
+    assert_eq!(Eisenstein::UNITS[1].associates(), {
+        let mut rotated = Eisenstein::UNITS;
+        rotated.rotate_left(1);
+        rotated
+    });
+    assert_eq!(
+        grid(3)
+            .map(|(a, b)| Eisenstein::new(a, b))
+            .filter(|z| z.is_unit())
+            .count(),
+        6
+    );
+    assert_eq!(Eisenstein::new(3, -2).to_string(), "3 - 2ω");
+    let (a, b) = (1u128 << 63, (1u128 << 63) - 1);
+    assert_eq!(
+        Eisenstein::new(i64::MIN, i64::MAX).norm(),
+        a * a + a * b + b * b
+    );
+    assert_eq!(Eisenstein::new(i64::MIN, i64::MIN).norm(), a * a);
+
+    // Eisenstein division
+    for z in grid(9).map(|(a, b)| Eisenstein::new(a, b)) {
+        for w in grid(4)
+            .map(|(a, b)| Eisenstein::new(a, b))
+            .filter(|&w| w != Eisenstein::ZERO)
+        {
+            let (quotient, remainder) = z.div_rem(w);
+            assert_eq!(quotient * w + remainder, z);
+            assert!(remainder.norm() * 4 <= w.norm() * 3, "{z} % {w}");
+            assert_eq!((z / w, z % w), (quotient, remainder));
+        }
+    }
+    let mut z = Eisenstein::new(5, 7);
+    z *= &Eisenstein::OMEGA;
+    z -= Eisenstein::ONE;
+    z %= Eisenstein::new(2, 0);
+    assert!(z.norm() < 4);
+    let z = Eisenstein::new(i64::MAX, i64::MIN);
+    assert_eq!(z.div_rem(Eisenstein::ONE), (z, Eisenstein::ZERO));
+    assert!(std::panic::catch_unwind(|| z.div_rem(z)).is_err());
+
+    // Z[√-2] is Euclidean, Z[√-5] isn't
+    type Z2 = QuadraticInt<-2>;
+    type Z5 = QuadraticInt<-5>;
+    assert!(Z2::is_euclidean() && !Z5::is_euclidean());
+    for z in grid(9).map(|(a, b)| Z2::new(a, b)) {
+        for w in grid(3)
+            .map(|(a, b)| Z2::new(a, b))
+            .filter(|&w| w != Z2::ZERO)
+        {
+            let (quotient, remainder) = z.div_rem(w).unwrap();
+            assert_eq!(quotient * w + remainder, z);
+            assert!(remainder.norm() < w.norm());
+            assert_eq!(
+                z.checked_div(w),
+                (remainder == Z2::ZERO).then_some(quotient)
+            );
+        }
+    }
+    let failures = grid(6)
+        .map(|(a, b)| Z5::new(a, b))
+        .filter(|&z| z.div_rem(Z5::new(2, 0)).is_none())
+        .count();
+    assert!(failures > 0);
+    // 6 = 2 · 3 = (1 + √-5)(1 - √-5)
+    assert_eq!(Z5::new(1, 1) * Z5::new(1, -1), Z5::from(6));
+    assert_eq!(Z5::from(6).checked_div(Z5::new(1, 1)), Some(Z5::new(1, -1)));
+    assert_eq!(Z5::from(6).checked_div(Z5::new(1, 2)), None);
+
+    // overflow panics instead of wrapping
+    let z = Z2::new(i64::MAX, i64::MIN);
+    assert_eq!(z.div_rem(Z2::ONE), Some((z, Z2::ZERO)));
+    assert!(std::panic::catch_unwind(|| Z5::new(i64::MAX, i64::MAX).norm()).is_err());
+    assert!(std::panic::catch_unwind(|| Z2::new(i64::MIN, 0).div_rem(-Z2::ONE)).is_err());
+    let z = QuadraticInt::<-1>::new(i64::MIN, i64::MIN);
+    assert!(std::panic::catch_unwind(|| z.div_rem(z)).is_err());
+
+    // units
+    assert_eq!(QuadraticInt::<-1>::units().count(), 4);
+    assert_eq!(Z2::units().collect::<Vec<_>>(), [Z2::ONE, -Z2::ONE]);
+    assert!(Z5::units().all(|u| u.is_unit()));
+
+    // conversions
+    let z = Z2::new(3, -2);
+    assert_complex_eq!(
+        Complex::from(z),
+        Complex::new(3f64, -2f64 * f64::sqrt(2f64))
+    );
+    assert_complex_eq!(
+        Complex::from(z * z.conj()),
+        Complex::new(z.norm() as f64, 0f64)
+    );
+    assert_eq!(z.to_string(), "3 - 2√-2");
+    let gaussian = GaussianInt::new(4, -7);
+    let quadratic = QuadraticInt::<-1>::from(gaussian);
+    assert_eq!(
+        quadratic * QuadraticInt::SQRT_D,
+        QuadraticInt::from(gaussian * GaussianInt::I)
+    );
+    assert_eq!(GaussianInt::from(quadratic), gaussian);
+    assert_eq!(quadratic.norm(), gaussian.norm());
+}
+
+#[cfg(feature = "rational")]
+#[test]
+fn complex_rational() {
+    use crate::gaussian::GaussianInt;
+    use crate::rational::ComplexRational;
+    use num_rational::BigRational;
+    use num_traits::{One, Zero};
+
+    let z = ComplexRational::from_fractions((1, 2), (-3, 4));
+    let w = ComplexRational::from(Complex::new(2, 5));
+
+    // exact arithmetic
+    assert_eq!(&z + &w, ComplexRational::from_fractions((5, 2), (17, 4)));
+    assert_eq!(&z - &w, ComplexRational::from_fractions((-3, 2), (-23, 4)));
+    assert_eq!(&z * &w, ComplexRational::from_fractions((19, 4), (1, 1)));
+    assert_eq!(&(&z / &w) * &w, z);
+    assert_eq!(z.clone() * w.inv(), &z / &w);
+    assert_eq!(-&z + z.clone(), ComplexRational::zero());
+    let mut u = z.clone();
+    u += &w;
+    u -= w.clone();
+    u *= &w;
+    u /= w.clone();
+    assert_eq!(u, z);
+
+    // reduction and normalisation of the denominators
+    assert_eq!(
+        ComplexRational::from_fractions((6, -8), (10, 4)),
+        ComplexRational::from_fractions((-3, 4), (5, 2))
+    );
+    let unreduced = ComplexRational(Complex::new(
+        BigRational::new_raw(4.into(), 6.into()),
+        BigRational::new_raw(0.into(), 3.into()),
+    ));
+    let reduced = unreduced.reduced();
+    assert_eq!(reduced, unreduced);
+    assert_eq!(
+        (reduced.real().numer(), reduced.imag().denom()),
+        (&2.into(), &1.into())
+    );
+    assert_eq!(z.denominator(), 4.into());
+    assert_eq!((&z * &ComplexRational::from(4)).denominator(), 1.into());
+
+    // conj, square_abs and powers
+    assert_eq!(&z * &z.conj(), ComplexRational::from(z.square_abs()));
+    assert_eq!(w.square_abs(), BigRational::from_integer(29.into()));
+    assert_eq!(ComplexRational::i().powi(4), ComplexRational::one());
+    assert_eq!(w.powi(-2), w.powi(2).inv());
+    assert_eq!(&w.powi(3) * &w.powi(-3), ComplexRational::one());
+    assert_eq!(ComplexRational::zero().powi(0), ComplexRational::one());
+    assert_eq!(z.powi(10).denominator(), (1u64 << 20).into());
+
+    // exact checks of the floating point functions
+    for (a, b) in [(3.0, 4.0), (0.5, -0.25), (-1.5, 2.0), (1e-3, 7.0)] {
+        let complex = Complex::new(a, b);
+        let exact = ComplexRational::from_complex(complex).unwrap();
+        assert_complex_eq!(exact.inv().to_complex(), complex.inv());
+        assert_complex_eq!(exact.powi(5).to_complex(), complex.powi(5), 1e-12);
+        assert_complex_eq!(exact.powi(-3).to_complex(), complex.powi(-3), 1e-12);
+    }
+    assert_eq!(
+        ComplexRational::from_complex(Complex::new(1.0, f64::INFINITY)),
+        None
+    );
+
+    // conversions and formatting
+    assert_eq!(ComplexRational::from(GaussianInt::new(2, 5)), w);
+    assert_eq!(Complex::<f64>::from(&z), Complex::new(0.5, -0.75));
+    let huge = ComplexRational::from(2).powi(2000);
+    assert_eq!(huge.to_complex(), Complex::new(f64::INFINITY, 0.0));
+    assert_eq!(z.to_string(), "1/2 - (3/4)i");
+    assert_eq!(w.to_string(), "2 + 5i");
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn fixed_point() {
+    use crate::fixed::{ComplexQ15, ComplexQ31, FixedComplex, Q15, Q31, QFormat};
+    use core::f32::consts::PI;
+
+    fn check_format<Q: QFormat>(ulp: f32) {
+        let points = (-8..8).flat_map(|a| (-8..8).map(move |b| (a as f32 / 8.0, b as f32 / 8.0)));
+        for (a, b) in points {
+            let complex = Complex::new(a, b);
+            let z = FixedComplex::<Q>::from(complex);
+            assert_eq!(Complex::<f32>::from(z), complex);
+
+            // products are exact up to the rounding of the result
+            let w = FixedComplex::<Q>::from(Complex::new(0.3f32, -0.7));
+            let product = Complex::<f32>::from(z * w);
+            assert_complex_eq!(product, complex * Complex::from(w), 2.0 * ulp);
+
+            let magnitude = Q::widen(z.approx_abs()) as f32 / (1u64 << Q::FRACTIONAL_BITS) as f32;
+            // magnitudes of 1 or more saturate
+            let lower = (complex.abs() * (1.0 - 0.0625) - ulp).min(1.0 - ulp);
+            assert!(lower <= magnitude && magnitude <= complex.abs() * (1.0 + 0.0625) + ulp);
+
+            if a != 0.0 || b != 0.0 {
+                let arg = FixedComplex::<Q>::angle_to_radians(z.arg());
+                let expected = if complex.arg() == PI {
+                    PI - ulp * PI
+                } else {
+                    complex.arg()
+                };
+                assert!((arg - expected).abs() < 16.0 * ulp, "arg of {complex}");
+            }
+        }
+
+        // rotations in all four quadrants keep the absolute value
+        let z = FixedComplex::<Q>::from(Complex::new(0.6f32, -0.2));
+        for degrees in (-180..180).step_by(15) {
+            let radians = degrees as f32 * PI / 180.0;
+            let rotated =
+                Complex::<f32>::from(z.rotate(FixedComplex::<Q>::angle_from_radians(radians)));
+            let expected = Complex::from(z) * Complex::new(radians.cos(), radians.sin());
+            assert!(
+                (rotated - expected).abs() < 32.0 * ulp,
+                "rotation by {degrees}°"
+            );
+        }
+        assert_eq!(FixedComplex::<Q>::default().arg(), Q::Raw::default());
+    }
+    check_format::<Q15>(1.0 / 32768.0);
+    check_format::<Q31>(1e-6);
+
+    // saturation and wrapping
+    let max = ComplexQ15::from_raw(i16::MAX, i16::MIN);
+    let step = ComplexQ15::from_raw(1, 1);
+    assert_eq!((max + step).raw(), (i16::MAX, i16::MIN + 1));
+    assert_eq!(max.wrapping_add(step).raw(), (i16::MIN, i16::MIN + 1));
+    assert_eq!((max - step).raw(), (i16::MAX - 1, i16::MIN));
+    assert_eq!(max.wrapping_sub(step).raw(), (i16::MAX - 1, i16::MAX));
+    assert_eq!((-max).raw(), (-i16::MAX, i16::MAX));
+    assert_eq!(max.conj().raw(), (i16::MAX, i16::MAX));
+    let minus_one = ComplexQ31::from_raw(i32::MIN, i32::MIN);
+    assert_eq!((minus_one * minus_one).raw(), (0, i32::MAX));
+    assert_eq!(minus_one.scale(i32::MIN).raw(), (i32::MAX, i32::MAX));
+    assert_eq!(
+        ComplexQ15::from(Complex::new(2.0, f32::NAN)).raw(),
+        (i16::MAX, 0)
+    );
+    assert_eq!(
+        ComplexQ15::from(Complex::new(-1.0, -2.0)).raw(),
+        (i16::MIN, i16::MIN)
+    );
+
+    // rounding of products, 1/2^15 * 1/2 rounds half up
+    let ulp = ComplexQ15::from_raw(1, -1);
+    assert_eq!(ulp.scale(1 << 14).raw(), (1, 0));
+
+    // reference and assign operators
+    let mut z = ComplexQ15::from(Complex::new(0.25, 0.5));
+    let w = ComplexQ15::from(Complex::new(0.5, 0.0));
+    z += &w;
+    z -= w;
+    z *= &w;
+    assert_eq!(z, &ComplexQ15::from(Complex::new(0.25, 0.5)) * &w);
+    assert_eq!(Complex::from(z), Complex::new(0.125, 0.25));
+
+    // binary angles wrap around at ±π
+    assert_eq!(ComplexQ15::angle_from_radians(PI / 2.0), 1 << 14);
+    assert_eq!(ComplexQ15::angle_from_radians(3.0 * PI / 2.0), -(1 << 14));
 }